@@ -1 +1,2 @@
-pub mod incorrect_format_error;
\ No newline at end of file
+pub mod app_error;
+pub mod incorrect_format_error;
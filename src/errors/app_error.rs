@@ -0,0 +1,64 @@
+use std::error;
+use std::fmt;
+
+/*
+    終了コード
+    0: 新しいデータを出力した
+    2: 新しいメールが見つからなかった
+    3: ワークブックの解析中に問題が見つかった
+    4: アクセストークンの取得、またはIMAPサーバへの認証に失敗した
+    5: IMAPサーバとの通信に失敗した
+    6: 添付ファイルの取得、または保存に失敗した
+    7: ワークブックの読み込みに失敗した
+    8: 出力ファイルの書き込みに失敗した
+    9: ワークブックのレイアウトが既知のどのレイアウトとも一致しなかった
+    10: コマンドライン引数が正しくなかった（引数の不足や値の誤り、組み合わせの矛盾）
+*/
+#[derive(Debug)]
+pub enum AppError {
+    Auth(String),
+    Imap(String),
+    NoNewMail,
+    Attachment(String),
+    Workbook(String),
+    UnknownLayout(String),
+    Parse(usize),
+    Output(String),
+    Config(String)
+}
+
+impl AppError {
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AppError::NoNewMail => 2,
+            AppError::Parse(_) => 3,
+            AppError::Auth(_) => 4,
+            AppError::Imap(_) => 5,
+            AppError::Attachment(_) => 6,
+            AppError::Workbook(_) => 7,
+            AppError::UnknownLayout(_) => 9,
+            AppError::Output(_) => 8,
+            AppError::Config(_) => 10
+        }
+    }
+
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AppError::Auth(message) => write!(f, "Authentication is failed: {}", message),
+            AppError::Imap(message) => write!(f, "IMAP error: {}", message),
+            AppError::NoNewMail => write!(f, "No new mail with a workbook was found"),
+            AppError::Attachment(message) => write!(f, "Failed to save an attachment: {}", message),
+            AppError::Workbook(message) => write!(f, "Failed to load the workbook: {}", message),
+            AppError::UnknownLayout(message) => write!(f, "Failed to detect the workbook layout: {}", message),
+            AppError::Parse(count) => write!(f, "{} error(s) found while parsing the workbook", count),
+            AppError::Output(message) => write!(f, "Failed to output json file: {}", message),
+            AppError::Config(message) => write!(f, "Invalid arguments: {}", message)
+        }
+    }
+}
+
+impl error::Error for AppError {}
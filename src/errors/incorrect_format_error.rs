@@ -1,3 +1,6 @@
+use std::error;
+use std::fmt;
+
 #[derive(Debug)]
 pub struct IncorrectFormatError {}
 
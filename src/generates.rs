@@ -1,6 +1,15 @@
+pub mod active_cases_generate;
+pub mod areas_summary_generate;
+pub mod breakdown_generate;
 pub mod inspections_summary_generate;
+pub mod inspections_time_series_generate;
 pub mod json;
 pub mod main_summary_generate;
 pub mod news_generate;
 pub mod patients_generate;
 pub mod patients_summary_generate;
+pub mod period_summary_generate;
+pub mod positivity_generate;
+pub mod reproduction_number_generate;
+pub mod statistics_generate;
+pub mod trend_generate;
@@ -0,0 +1,210 @@
+use calamine::{DataType, Range};
+use chrono::NaiveDate;
+use crate::generates::main_summary_generate::latest_row;
+use crate::structs::{
+    active_cases::{ActiveCases, ActiveCasesData, Reconciliation},
+    column_series::InspectionsTimeSeries,
+    diagnostics::Diagnostics,
+    layout::SheetLayout,
+    leave_status::LeaveStatus,
+    patient::Patient,
+    time_series::TimeSeries
+};
+use crate::utils::date_format::convert_jst_to_date;
+
+// PCR検査件数ワークシートの陽性患者数、退院、死亡の累計から、日ごとの療養中の患者数を求める
+// 累計の無い日は、前の日の累計のままとする
+// 最新の累計は陽性者の属性から数えた人数と照合し、一致しなければ注意として記録する
+pub fn active_cases_generate(inspections_time_series: &InspectionsTimeSeries, patients: &[Patient], range: &Range<DataType>, sheet_layout: &SheetLayout, sheet: &str, diagnostics: &mut Diagnostics) -> ActiveCases {
+
+    let positives: TimeSeries<i64> = carried_column(inspections_time_series, "陽性患者数");
+    let recovered: TimeSeries<i64> = carried_column(inspections_time_series, "退院");
+    let deaths: TimeSeries<i64> = carried_column(inspections_time_series, "死亡");
+
+    let mut active_cases: ActiveCases = ActiveCases {
+        data: Vec::new(),
+        reconciliations: Vec::new(),
+        last_update: inspections_time_series.last_update
+    };
+
+    for (date, positive) in positives.iter() {
+        let recovered: i64 = carried_value(&recovered, date);
+        let deaths: i64 = carried_value(&deaths, date);
+
+        active_cases.data.push(ActiveCasesData {
+            date,
+            positives: *positive,
+            recovered,
+            deaths,
+            active: positive - recovered - deaths
+        });
+    }
+
+    let last: ActiveCasesData = match active_cases.data.last() {
+        Some(last) => last.clone(),
+        None => return active_cases
+    };
+
+    // 照合する項目と、ワークシートの最新の累計、陽性者の属性から数えた人数
+    let counts: [(&str, i64, i64); 3] = [
+        ("陽性患者数", last.positives, patients.len() as i64),
+        ("退院", last.recovered, patients.iter().filter(|patient| patient.leave_status == LeaveStatus::Discharged).count() as i64),
+        ("死亡", last.deaths, patients.iter().filter(|patient| patient.leave_status == LeaveStatus::Died).count() as i64)
+    ];
+
+    let date_column: usize = sheet_layout.column("日付").unwrap_or(0);
+    let row: Option<usize> = latest_row(range, date_column);
+
+    for (attr, aggregate, count) in counts {
+
+        if aggregate != count {
+            if let (Some(row), Some(column)) = (row, sheet_layout.column(attr)) {
+                diagnostics.warn_cell(sheet, range, (row, column), &format!("the number of patients in the patient list ({})", count));
+            }
+        }
+
+        active_cases.reconciliations.push(Reconciliation {
+            attr: attr.to_string(),
+            aggregate,
+            patients: count
+        });
+    }
+
+    active_cases
+
+}
+
+// 列の累計を、最初の日から最後の日まで隙間なく並べる
+fn carried_column(inspections_time_series: &InspectionsTimeSeries, attr: &str) -> TimeSeries<i64> {
+
+    let points: Vec<(NaiveDate, i64)> = inspections_time_series.columns.iter()
+        .filter(|column| column.attr == attr)
+        .flat_map(|column| column.data.iter())
+        .map(|data| (convert_jst_to_date(data.date), data.value))
+        .collect();
+
+    let mut last: i64 = 0;
+
+    TimeSeries::from_points(points).map(|value| {
+        if let Some(value) = value {
+            last = *value;
+        }
+        last
+    })
+
+}
+
+// 列の最初の日より前は0、最後の日より後は最後の累計とする
+fn carried_value(series: &TimeSeries<i64>, date: NaiveDate) -> i64 {
+
+    match (series.get(date), series.start) {
+        (Some(value), _) => *value,
+        (None, Some(start)) if date > start => series.values.last().copied().unwrap_or(0),
+        _ => 0
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::generates::inspections_time_series_generate::inspections_time_series_generate;
+    use crate::structs::{age_group::AgeGroup, diagnostics::Severity, gender::Gender, municipality::Place};
+    use crate::utils::date_format::jst;
+    use crate::utils::detect_layout::LAYOUTS;
+    use chrono::TimeZone;
+
+    const SHEET: &str = "PCR検査件数";
+
+    fn sheet_layout() -> &'static SheetLayout {
+        LAYOUTS[0].sheet(SHEET).unwrap()
+    }
+
+    // 新しい日付から順に、2021年8月1日からの日数と陽性患者数、退院、死亡の累計を並べたワークシート
+    fn range() -> Range<DataType> {
+        let rows: [(u32, f64, f64, f64); 3] = [(3, 20.0, 5.0, 2.0), (1, 15.0, 4.0, 1.0), (0, 10.0, 2.0, 1.0)];
+        let mut range: Range<DataType> = Range::new((0, 0), (rows.len() as u32 - 1, 10));
+
+        for (i, (day, positives, recovered, deaths)) in rows.iter().enumerate() {
+            let i: u32 = i as u32;
+            range.set_value((i, 0), DataType::DateTime(44409.0 + *day as f64));
+            range.set_value((i, sheet_layout().column("陽性患者数").unwrap() as u32), DataType::Float(*positives));
+            range.set_value((i, sheet_layout().column("退院").unwrap() as u32), DataType::Float(*recovered));
+            range.set_value((i, sheet_layout().column("死亡").unwrap() as u32), DataType::Float(*deaths));
+        }
+
+        range
+    }
+
+    // 退院した人数と死亡した人数を含む、合計total人の陽性者
+    fn patients(total: usize, discharged: usize, died: usize) -> Vec<Patient> {
+        (0..total).map(|i| Patient {
+            number: i as i32 + 1,
+            release_date: None,
+            place: None,
+            municipality: Place::Unknown,
+            age: AgeGroup::Unknown,
+            gender: Gender::Unknown,
+            leave: None,
+            leave_status: if i < discharged {
+                LeaveStatus::Discharged
+            } else if i < discharged + died {
+                LeaveStatus::Died
+            } else {
+                LeaveStatus::Hospitalized
+            }
+        }).collect()
+    }
+
+    fn generate(patients: &[Patient]) -> (ActiveCases, Diagnostics) {
+        let range: Range<DataType> = range();
+        let mut diagnostics: Diagnostics = Diagnostics::new();
+        let inspections_time_series: InspectionsTimeSeries = inspections_time_series_generate(
+            &range, sheet_layout(), jst().with_ymd_and_hms(2021, 8, 5, 0, 0, 0).unwrap(), SHEET, &mut diagnostics
+        );
+
+        let active_cases: ActiveCases = active_cases_generate(&inspections_time_series, patients, &range, sheet_layout(), SHEET, &mut diagnostics);
+
+        (active_cases, diagnostics)
+    }
+
+    #[test]
+    fn computes_daily_active_cases() {
+        let (active_cases, _) = generate(&patients(20, 5, 2));
+
+        assert_eq!(
+            active_cases.data.iter().map(|data| (data.date.format("%m-%d").to_string(), data.active)).collect::<Vec<(String, i64)>>(),
+            vec![
+                (String::from("08-01"), 7),
+                (String::from("08-02"), 10),
+                // 行の無い日は、前の日の累計のままとする
+                (String::from("08-03"), 10),
+                (String::from("08-04"), 13)
+            ]
+        );
+    }
+
+    #[test]
+    fn reconciles_matching_patient_list() {
+        let (active_cases, diagnostics) = generate(&patients(20, 5, 2));
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(
+            active_cases.reconciliations.iter().map(|reconciliation| (reconciliation.attr.as_str(), reconciliation.aggregate, reconciliation.patients)).collect::<Vec<(&str, i64, i64)>>(),
+            vec![("陽性患者数", 20, 20), ("退院", 5, 5), ("死亡", 2, 2)]
+        );
+    }
+
+    #[test]
+    fn warns_on_mismatch_with_patient_list() {
+        let (active_cases, diagnostics) = generate(&patients(20, 4, 2));
+
+        assert_eq!(active_cases.reconciliations[1].patients, 4);
+        assert_eq!(diagnostics.items.len(), 1);
+        assert!(diagnostics.items[0].severity == Severity::Warning);
+        assert_eq!(diagnostics.items[0].cell, "D1");
+        assert!(diagnostics.items[0].expected.contains("(4)"));
+    }
+
+}
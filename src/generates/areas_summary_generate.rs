@@ -0,0 +1,105 @@
+use chrono::{DateTime, FixedOffset};
+use crate::structs::{
+    area_summary::{AreaSummary, AreasSummary},
+    municipality::{Municipality, MunicipalityDictionary, Place},
+    patient::Patient,
+    time_series::TimeSeries
+};
+
+// 陽性者の居住地から、市区町村、地域、保健所の管轄区域ごとの日ごと、週ごとの陽性者数を求める
+// 日ごとの陽性者数は、どの区域も全ての陽性者の最初の公表日から最後の公表日までの範囲で揃える
+pub fn areas_summary_generate(patients: &[Patient], dictionary: &'static MunicipalityDictionary, last_update: DateTime<FixedOffset>) -> AreasSummary {
+
+    let all_dates: Vec<DateTime<FixedOffset>> = patients.iter().filter_map(|patient| patient.release_date).collect();
+    let all: TimeSeries<i64> = TimeSeries::from_events(&all_dates);
+
+    let mut areas_summary: AreasSummary = AreasSummary {
+        municipalities: Vec::new(),
+        regions: Vec::new(),
+        health_centers: Vec::new(),
+        outside: 0,
+        unknown: 0,
+        last_update
+    };
+
+    for patient in patients {
+        match patient.municipality {
+            Place::OutsidePrefecture(_) => areas_summary.outside += 1,
+            Place::Unknown => areas_summary.unknown += 1,
+            Place::Municipality(_) => {}
+        }
+    }
+
+    for municipality in dictionary.municipalities {
+        areas_summary.municipalities.push(area_summary_generate(
+            municipality.name,
+            Some(municipality.code.to_string()),
+            &[municipality],
+            patients,
+            &all
+        ));
+    }
+
+    let regions: Vec<&str> = unique(dictionary.municipalities.iter().map(|municipality| municipality.region).collect());
+
+    for region in regions {
+        let municipalities: Vec<&Municipality> = dictionary.municipalities.iter()
+            .filter(|municipality| municipality.region == region)
+            .collect();
+        areas_summary.regions.push(area_summary_generate(region, None, &municipalities, patients, &all));
+    }
+
+    let health_centers: Vec<&str> = unique(dictionary.municipalities.iter().map(|municipality| municipality.health_center).collect());
+
+    for health_center in health_centers {
+        let municipalities: Vec<&Municipality> = dictionary.municipalities.iter()
+            .filter(|municipality| municipality.health_center == health_center)
+            .collect();
+        areas_summary.health_centers.push(area_summary_generate(health_center, None, &municipalities, patients, &all));
+    }
+
+    areas_summary
+
+}
+
+// 市区町村の集まりを1つの区域として、陽性者数を数える
+fn area_summary_generate(name: &str, code: Option<String>, municipalities: &[&Municipality], patients: &[Patient], all: &TimeSeries<i64>) -> AreaSummary {
+
+    let area_patients: Vec<&Patient> = patients.iter()
+        .filter(|patient| match patient.municipality {
+            Place::Municipality(municipality) => municipalities.iter().any(|area| area.code == municipality.code),
+            _ => false
+        })
+        .collect();
+
+    let dates: Vec<DateTime<FixedOffset>> = area_patients.iter().filter_map(|patient| patient.release_date).collect();
+    let counts: TimeSeries<i64> = TimeSeries::from_events(&dates);
+    let daily: TimeSeries<i64> = TimeSeries::from_points(all.iter()
+        .map(|(date, _)| (date, counts.get(date).copied().unwrap_or(0)))
+        .collect()).fill(0);
+
+    AreaSummary {
+        name: name.to_string(),
+        code,
+        population: municipalities.iter().map(|municipality| municipality.population).sum(),
+        total: area_patients.len() as i64,
+        weekly: daily.weekly(),
+        daily
+    }
+
+}
+
+// 最初に現れた順を保ったまま、重複を取り除く
+fn unique(names: Vec<&'static str>) -> Vec<&'static str> {
+
+    let mut unique: Vec<&'static str> = Vec::new();
+
+    for name in names {
+        if !unique.contains(&name) {
+            unique.push(name);
+        }
+    }
+
+    unique
+
+}
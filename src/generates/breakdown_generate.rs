@@ -0,0 +1,139 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate};
+use std::collections::BTreeMap;
+use crate::structs::{breakdown::{Breakdown, BreakdownData}, patient::Patient, time_series::TimeSeries};
+use crate::utils::date_format::convert_jst_to_date;
+
+// 陽性者をkeyで区分し、区分ごとの累計と直近7日間、週ごとの陽性者数を求める
+// 区分はkeyの順に並べ、labelが空の区分は不明とする
+pub fn breakdown_generate<K: Ord + Copy, F: Fn(&Patient) -> K, L: Fn(K) -> String>(patients: &[Patient], key: F, label: L, last_update: DateTime<FixedOffset>) -> Breakdown {
+
+    let all_dates: Vec<DateTime<FixedOffset>> = patients.iter().filter_map(|patient| patient.release_date).collect();
+    let weeks: Vec<NaiveDate> = TimeSeries::from_events(&all_dates).weekly().into_iter().map(|(start, _)| start).collect();
+
+    let today: NaiveDate = convert_jst_to_date(last_update);
+    let seven_days_ago: NaiveDate = today - Duration::days(7);
+
+    let mut groups: BTreeMap<K, Vec<&Patient>> = BTreeMap::new();
+
+    for patient in patients {
+        groups.entry(key(patient)).or_default().push(patient);
+    }
+
+    let mut breakdown: Breakdown = Breakdown {
+        weeks: weeks.clone(),
+        data: Vec::new(),
+        last_update
+    };
+
+    for (group, group_patients) in groups {
+
+        let dates: Vec<DateTime<FixedOffset>> = group_patients.iter().filter_map(|patient| patient.release_date).collect();
+        let weekly: BTreeMap<NaiveDate, i64> = TimeSeries::from_events(&dates).weekly().into_iter().collect();
+
+        let label: String = match label(group) {
+            label if label.is_empty() => String::from("不明"),
+            label => label
+        };
+
+        breakdown.data.push(BreakdownData {
+            label,
+            total: group_patients.len() as i64,
+            last_seven_days: dates.iter()
+                .map(|date| convert_jst_to_date(*date))
+                .filter(|date| *date > seven_days_ago && *date <= today)
+                .count() as i64,
+            weekly: weeks.iter().map(|week| weekly.get(week).copied().unwrap_or(0)).collect()
+        });
+    }
+
+    breakdown
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::structs::{age_group::AgeGroup, gender::Gender, leave_status::LeaveStatus, municipality::Place};
+    use crate::utils::date_format::{convert_date_to_jst, jst};
+    use chrono::TimeZone;
+
+    fn ymd(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2021, 8, day).unwrap()
+    }
+
+    // 8月の公表日（Noneは日付不明）と年代、性別から陽性者を作る
+    fn patient(day: Option<u32>, age: AgeGroup, gender: Gender) -> Patient {
+        Patient {
+            number: 0,
+            release_date: day.map(|day| convert_date_to_jst(ymd(day))),
+            place: None,
+            municipality: Place::Unknown,
+            age,
+            gender,
+            leave: None,
+            leave_status: LeaveStatus::Unknown
+        }
+    }
+
+    fn patients() -> Vec<Patient> {
+        // 2021年8月2日と9日は月曜日
+        vec![
+            patient(Some(2), AgeGroup::Decade(20), Gender::Male),
+            patient(Some(8), AgeGroup::Decade(20), Gender::Female),
+            patient(Some(9), AgeGroup::UnderTen, Gender::Unknown),
+            patient(Some(15), AgeGroup::Decade(20), Gender::Male),
+            patient(Some(15), AgeGroup::Unknown, Gender::Female),
+            patient(None, AgeGroup::NinetyAndOver, Gender::Male)
+        ]
+    }
+
+    fn counts(breakdown: &Breakdown) -> Vec<(&str, i64, i64)> {
+        breakdown.data.iter().map(|data| (data.label.as_str(), data.total, data.last_seven_days)).collect()
+    }
+
+    fn last_update() -> DateTime<FixedOffset> {
+        jst().with_ymd_and_hms(2021, 8, 15, 20, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn counts_age_groups_with_unknown() {
+        let breakdown: Breakdown = breakdown_generate(&patients(), |patient| patient.age, |age| age.label(), last_update());
+
+        assert_eq!(counts(&breakdown), vec![
+            ("10歳未満", 1, 1),
+            ("20代", 3, 1),
+            ("90歳以上", 1, 0),
+            ("不明", 1, 1)
+        ]);
+    }
+
+    #[test]
+    fn counts_genders_with_unknown() {
+        let breakdown: Breakdown = breakdown_generate(&patients(), |patient| patient.gender, |gender| gender.label(), last_update());
+
+        assert_eq!(counts(&breakdown), vec![
+            ("男性", 3, 1),
+            ("女性", 2, 1),
+            ("不明", 1, 1)
+        ]);
+    }
+
+    #[test]
+    fn buckets_weeks_from_monday() {
+        let breakdown: Breakdown = breakdown_generate(&patients(), |patient| patient.age, |age| age.label(), last_update());
+
+        assert_eq!(breakdown.weeks, vec![ymd(2), ymd(9)]);
+        assert_eq!(
+            breakdown.data.iter().map(|data| (data.label.as_str(), data.weekly.clone())).collect::<Vec<(&str, Vec<i64>)>>(),
+            vec![
+                ("10歳未満", vec![0, 1]),
+                ("20代", vec![2, 1]),
+                // 公表日の分からない陽性者は、どの週にも数えない
+                ("90歳以上", vec![0, 0]),
+                ("不明", vec![0, 1])
+            ]
+        );
+    }
+
+}
@@ -1,29 +1,251 @@
 use calamine::DataType;
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, FixedOffset};
 use crate::{SumData, Summary};
+use crate::structs::{correction_policy::CorrectionPolicy, diagnostics::Diagnostics, layout::SheetLayout, time_series::TimeSeries};
+use crate::utils::date_format::{convert_date_to_jst, convert_jst_to_date, convert_naive_to_jst};
 
 use calamine::{Range};
 
-pub fn inspections_summary_generate(range: Range<DataType>, last_update: DateTime<Local>) -> Summary {
+pub fn inspections_summary_generate(range: Range<DataType>, sheet_layout: &SheetLayout, last_update: DateTime<FixedOffset>, policy: CorrectionPolicy, sheet: &str, diagnostics: &mut Diagnostics) -> Summary {
 
     let mut inspections_summary: Summary = Summary {
         data: Vec::new(),
-        last_update: last_update
+        undated: None,
+        last_update
     };
 
+    let date_column: usize = sheet_layout.column("日付").unwrap_or(0);
+    let sum_column: usize = sheet_layout.column("検査実施人数").unwrap_or(1);
+
+    // 日付と累計を読み出し、日付の順に並べる
+    let mut cumulatives: Vec<(DateTime<FixedOffset>, i64, usize)> = Vec::new();
+
+    for (i, row) in range.rows().enumerate().rev() {
+
+        let date = match row.get(date_column).and_then(|cell| cell.as_datetime()) {
+            Some(date) => date,
+            None => {
+                diagnostics.push_cell(sheet, &range, (i, date_column), "date");
+                continue;
+            }
+        };
+
+        let sum: i64 = match row.get(sum_column) {
+            Some(cell) if cell.is_float() => cell.get_float().unwrap() as i64,
+            // 空欄の日は報告が無かったものとして、後で補う
+            Some(DataType::Empty) | None => {
+                diagnostics.warn_cell(sheet, &range, (i, sum_column), "number");
+                continue;
+            },
+            Some(_) => {
+                diagnostics.push_cell(sheet, &range, (i, sum_column), "number");
+                continue;
+            }
+        };
+
+        cumulatives.push((convert_naive_to_jst(date), sum, i));
+    }
+
+    cumulatives.sort_by_key(|(date, _, _)| *date);
+
+    for pair in cumulatives.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            diagnostics.warn_cell(sheet, &range, (pair[1].2, date_column), "a date not repeated");
+        }
+    }
+
+    // 報告の無かった日はNoneになる
+    let cumulatives: TimeSeries<Option<(i64, usize)>> = TimeSeries::from_points(cumulatives.into_iter()
+        .map(|(date, sum, i)| (convert_jst_to_date(date), (sum, i)))
+        .collect());
+
     let mut last_sum: i64 = 0;
+    let mut gap: usize = 0;
+
+    for (date, cumulative) in cumulatives.iter() {
+
+        let (sum, i): (i64, usize) = match cumulative {
+            Some(cumulative) => *cumulative,
+            None => {
+                inspections_summary.data.push(SumData {
+                    date: convert_date_to_jst(date),
+                    sum: 0,
+                    note: Some("報告なし".to_string())
+                });
+                gap += 1;
+                continue;
+            }
+        };
+
+        let delta: i64 = sum - last_sum;
+        let mut note: Option<String> = None;
+        let mut today: i64 = delta;
+
+        if delta < 0 {
+            diagnostics.warn_cell(sheet, &range, (i, sum_column), &format!("a cumulative count not less than the previous one ({})", last_sum));
 
-    for row in range.rows().rev() {
-        let sum: i64 = row[1].get_float().unwrap() as i64;
+            match policy {
+                CorrectionPolicy::Keep => {
+                    note = Some(format!("累計が{}減少する訂正", -delta));
+                },
+                CorrectionPolicy::Clamp => {
+                    note = Some(format!("累計が{}減少する訂正（0として計上）", -delta));
+                    today = 0;
+                },
+                CorrectionPolicy::Redistribute => {
+                    note = Some(format!("累計が{}減少する訂正（前の日から差し引き）", -delta));
+                    today = subtract_from_previous(&mut inspections_summary.data, -delta);
+                }
+            }
+        } else if gap > 0 {
+            if policy == CorrectionPolicy::Redistribute {
+                // まとめて計上された値を、報告の無かった日を含めて按分する
+                let days: i64 = gap as i64 + 1;
+                let length: usize = inspections_summary.data.len();
+
+                for data in &mut inspections_summary.data[length - gap..] {
+                    data.sum = delta / days;
+                    data.note = Some(format!("報告の無い日を含む{}日間で按分", days));
+                }
+
+                note = Some(format!("報告の無い日を含む{}日間で按分", days));
+                today = delta / days + delta % days;
+            } else {
+                note = Some(format!("報告の無い{}日分を含む", gap));
+            }
+        }
 
         inspections_summary.data.push(SumData {
-            date: DateTime::from_utc(row[0].as_datetime().unwrap(), Utc) as DateTime<Utc>,
-            sum: sum - last_sum
+            date: convert_date_to_jst(date),
+            sum: today,
+            note
         });
 
         last_sum = sum;
+        gap = 0;
+    }
+
+    inspections_summary
+
+}
+
+// 訂正で減った分を新しい日から順に差し引き、差し引ききれなかった分を返す
+fn subtract_from_previous(data: &mut [SumData], amount: i64) -> i64 {
+
+    let mut remaining: i64 = amount;
+
+    for previous in data.iter_mut().rev() {
+        if remaining == 0 {
+            break;
+        }
+
+        let subtracted: i64 = remaining.min(previous.sum.max(0));
+
+        if subtracted > 0 {
+            previous.sum -= subtracted;
+            previous.note = Some("後日の訂正を反映".to_string());
+            remaining -= subtracted;
+        }
+    }
+
+    -remaining
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::detect_layout::LAYOUTS;
+    use crate::utils::date_format::jst;
+    use chrono::TimeZone;
+
+    // 2021年8月1日からの日数と累計（Noneは報告の無い日）のワークシート
+    fn range(cumulatives: &[(u32, Option<f64>)]) -> Range<DataType> {
+        let mut range: Range<DataType> = Range::new((0, 0), (cumulatives.len() as u32 - 1, 10));
+
+        for (i, (day, sum)) in cumulatives.iter().enumerate() {
+            range.set_value((i as u32, 0), DataType::DateTime(44409.0 + *day as f64));
+            if let Some(sum) = sum {
+                range.set_value((i as u32, 1), DataType::Float(*sum));
+            }
+        }
+
+        range
+    }
+
+    fn generate(cumulatives: &[(u32, Option<f64>)], policy: CorrectionPolicy) -> (Vec<(i64, Option<String>)>, Diagnostics) {
+        let mut diagnostics: Diagnostics = Diagnostics::new();
+        let last_update: DateTime<FixedOffset> = jst().with_ymd_and_hms(2021, 8, 31, 0, 0, 0).unwrap();
+        let summary: Summary = inspections_summary_generate(
+            range(cumulatives), LAYOUTS[0].sheet("PCR検査件数").unwrap(), last_update, policy, "PCR検査件数", &mut diagnostics
+        );
+
+        (summary.data.into_iter().map(|data| (data.sum, data.note)).collect(), diagnostics)
+    }
+
+    fn sums(data: &[(i64, Option<String>)]) -> Vec<i64> {
+        data.iter().map(|(sum, _)| *sum).collect()
+    }
+
+    const CORRECTED: [(u32, Option<f64>); 4] = [(0, Some(10.0)), (1, Some(20.0)), (2, Some(15.0)), (3, Some(25.0))];
+    const GAP: [(u32, Option<f64>); 4] = [(0, Some(10.0)), (1, None), (2, None), (3, Some(17.0))];
+
+    #[test]
+    fn keep_outputs_negative_correction() {
+        let (data, diagnostics) = generate(&CORRECTED, CorrectionPolicy::Keep);
+
+        assert_eq!(sums(&data), vec![10, 10, -5, 10]);
+        assert_eq!(data[2].1.as_deref(), Some("累計が5減少する訂正"));
+        assert_eq!(diagnostics.error_count(), 0);
+        assert_eq!(diagnostics.items.len(), 1);
+    }
+
+    #[test]
+    fn clamp_outputs_zero_for_correction() {
+        let (data, _) = generate(&CORRECTED, CorrectionPolicy::Clamp);
+
+        assert_eq!(sums(&data), vec![10, 10, 0, 10]);
+        assert_eq!(data[2].1.as_deref(), Some("累計が5減少する訂正（0として計上）"));
+    }
+
+    #[test]
+    fn redistribute_subtracts_correction_from_previous_days() {
+        let (data, _) = generate(&CORRECTED, CorrectionPolicy::Redistribute);
+
+        assert_eq!(sums(&data), vec![10, 5, 0, 10]);
+        assert_eq!(data[1].1.as_deref(), Some("後日の訂正を反映"));
+        assert_eq!(sums(&data).iter().sum::<i64>(), 25);
     }
 
-    return inspections_summary;
+    #[test]
+    fn redistribute_carries_over_correction_larger_than_previous_days() {
+        let (data, _) = generate(&[(0, Some(10.0)), (1, Some(4.0))], CorrectionPolicy::Redistribute);
+
+        assert_eq!(sums(&data), vec![4, 0]);
+
+        let (data, _) = generate(&[(0, Some(3.0)), (1, Some(5.0)), (2, Some(0.0))], CorrectionPolicy::Redistribute);
+
+        assert_eq!(sums(&data), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn keep_lumps_gap_into_reported_day() {
+        let (data, diagnostics) = generate(&GAP, CorrectionPolicy::Keep);
+
+        assert_eq!(sums(&data), vec![10, 0, 0, 7]);
+        assert_eq!(data[1].1.as_deref(), Some("報告なし"));
+        assert_eq!(data[3].1.as_deref(), Some("報告の無い2日分を含む"));
+        assert_eq!(diagnostics.items.len(), 2);
+    }
+
+    #[test]
+    fn redistribute_spreads_gap_over_unreported_days() {
+        let (data, _) = generate(&GAP, CorrectionPolicy::Redistribute);
+
+        assert_eq!(sums(&data), vec![10, 2, 2, 3]);
+        assert_eq!(data[1].1.as_deref(), Some("報告の無い日を含む3日間で按分"));
+        assert_eq!(sums(&data).iter().sum::<i64>(), 17);
+    }
 
 }
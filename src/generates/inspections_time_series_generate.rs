@@ -0,0 +1,154 @@
+use calamine::{DataType, Range};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use crate::structs::{
+    column_series::{ColumnData, ColumnSeries, InspectionsTimeSeries},
+    diagnostics::Diagnostics,
+    layout::SheetLayout,
+    time_series::TimeSeries
+};
+use crate::utils::date_format::convert_date_to_jst;
+
+// 日ごとの推移を出力する列（レイアウトに無い列は出力しない）
+const COLUMNS: [&str; 10] = ["検査実施人数", "陽性患者数", "入院中・入院調整中", "高度重症病床", "その他", "宿泊施設", "自宅療養", "死亡", "退院", "調整中"];
+
+pub fn inspections_time_series_generate(range: &Range<DataType>, sheet_layout: &SheetLayout, last_update: DateTime<FixedOffset>, sheet: &str, diagnostics: &mut Diagnostics) -> InspectionsTimeSeries {
+
+    let date_column: usize = sheet_layout.column("日付").unwrap_or(0);
+
+    let mut columns: Vec<ColumnSeries> = Vec::new();
+
+    for attr in COLUMNS {
+        let column: usize = match sheet_layout.column(attr) {
+            Some(column) => column,
+            None => continue
+        };
+
+        // 日付の読めない行は検査件数の集計で記録しているので、ここでは読み飛ばす
+        let mut points: Vec<(NaiveDate, i64)> = Vec::new();
+
+        for (i, row) in range.rows().enumerate() {
+            let date: NaiveDate = match row.get(date_column).and_then(|cell| cell.as_datetime()) {
+                Some(date) => date.date(),
+                None => continue
+            };
+
+            match row.get(column) {
+                Some(cell) if cell.is_float() => points.push((date, cell.get_float().unwrap() as i64)),
+                // 列が追加される前の日は空欄なので、その日の値は無いものとする
+                Some(DataType::Empty) | None => {},
+                Some(_) => diagnostics.push_cell(sheet, range, (i, column), "number")
+            }
+        }
+
+        // 前日比は前日の値がある場合のみ求め、前日の行が無い日は無いものとする
+        let values: TimeSeries<Option<i64>> = TimeSeries::from_points(points);
+        let mut data: Vec<ColumnData> = Vec::new();
+        let mut previous_value: Option<i64> = None;
+
+        for (date, value) in values.iter() {
+            if let Some(value) = value {
+                data.push(ColumnData {
+                    date: convert_date_to_jst(date),
+                    value: *value,
+                    delta: previous_value.map(|previous_value| value - previous_value)
+                });
+            }
+
+            previous_value = *value;
+        }
+
+        columns.push(ColumnSeries {
+            attr: attr.to_string(),
+            data
+        });
+    }
+
+    InspectionsTimeSeries {
+        columns,
+        last_update
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::date_format::jst;
+    use crate::utils::detect_layout::LAYOUTS;
+    use chrono::TimeZone;
+
+    // 2021年8月1日からの日数と、各列の値（列の順はレイアウトと同じ）のワークシート
+    fn range(rows: &[(u32, [f64; 10])]) -> Range<DataType> {
+        let mut range: Range<DataType> = Range::new((0, 0), (rows.len() as u32 - 1, 10));
+
+        for (i, (day, values)) in rows.iter().enumerate() {
+            range.set_value((i as u32, 0), DataType::DateTime(44409.0 + *day as f64));
+            for (j, value) in values.iter().enumerate() {
+                range.set_value((i as u32, j as u32 + 1), DataType::Float(*value));
+            }
+        }
+
+        range
+    }
+
+    fn generate(rows: &[(u32, [f64; 10])]) -> InspectionsTimeSeries {
+        let mut diagnostics: Diagnostics = Diagnostics::new();
+        let time_series: InspectionsTimeSeries = inspections_time_series_generate(
+            &range(rows),
+            LAYOUTS[0].sheet("PCR検査件数").unwrap(),
+            jst().with_ymd_and_hms(2021, 8, 10, 0, 0, 0).unwrap(),
+            "PCR検査件数",
+            &mut diagnostics
+        );
+
+        assert!(diagnostics.is_empty());
+        time_series
+    }
+
+    fn column(time_series: &InspectionsTimeSeries, attr: &str) -> Vec<(NaiveDate, i64, Option<i64>)> {
+        time_series.columns.iter()
+            .find(|column| column.attr == attr)
+            .unwrap()
+            .data.iter()
+            .map(|data| (data.date.date_naive(), data.value, data.delta))
+            .collect()
+    }
+
+    fn ymd(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2021, 8, day).unwrap()
+    }
+
+    #[test]
+    fn outputs_one_series_per_column() {
+        let time_series: InspectionsTimeSeries = generate(&[
+            (1, [200.0, 20.0, 2.0, 10.0, 1.0, 1.0, 3.0, 4.0, 1.0, 0.0]),
+            (0, [100.0, 10.0, 1.0, 5.0, 0.0, 1.0, 2.0, 2.0, 0.0, 0.0])
+        ]);
+
+        assert_eq!(
+            time_series.columns.iter().map(|column| column.attr.as_str()).collect::<Vec<&str>>(),
+            COLUMNS.to_vec()
+        );
+        assert_eq!(column(&time_series, "検査実施人数"), vec![(ymd(1), 100, None), (ymd(2), 200, Some(100))]);
+        assert_eq!(column(&time_series, "死亡"), vec![(ymd(1), 0, None), (ymd(2), 1, Some(1))]);
+    }
+
+    #[test]
+    fn leaves_delta_null_after_missing_day() {
+        let time_series: InspectionsTimeSeries = generate(&[
+            (0, [100.0; 10]),
+            (1, [110.0; 10]),
+            (3, [130.0; 10]),
+            (4, [125.0; 10])
+        ]);
+
+        assert_eq!(column(&time_series, "検査実施人数"), vec![
+            (ymd(1), 100, None),
+            (ymd(2), 110, Some(10)),
+            (ymd(4), 130, None),
+            (ymd(5), 125, Some(-5))
+        ]);
+    }
+
+}
@@ -1,3 +1,12 @@
+pub mod jsonize_active_cases_generate;
+pub mod jsonize_areas_summary_generate;
+pub mod jsonize_breakdown_generate;
+pub mod jsonize_inspections_time_series_generate;
 pub mod jsonize_main_summary_generate;
 pub mod jsonize_summary_generate;
 pub mod jsonize_patients_generate;
+pub mod jsonize_period_summary_generate;
+pub mod jsonize_positivity_generate;
+pub mod jsonize_reproduction_number_generate;
+pub mod jsonize_statistics_generate;
+pub mod jsonize_trend_generate;
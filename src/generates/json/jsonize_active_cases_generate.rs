@@ -0,0 +1,26 @@
+use crate::utils::date_format::{convert_date_to_jst, convert_datetime_to_date_and_time};
+use chrono::SecondsFormat;
+use crate::structs::{
+    active_cases::ActiveCases,
+    json::jsonize_active_cases::{JsonizeActiveCases, JsonizeActiveCasesData, JsonizeReconciliation}
+};
+
+pub fn jsonize_active_cases_generate(active_cases: ActiveCases) -> String {
+
+    serde_json::to_string_pretty(&JsonizeActiveCases {
+        data: active_cases.data.into_iter().map(|data| JsonizeActiveCasesData {
+            date: convert_date_to_jst(data.date).to_rfc3339_opts(SecondsFormat::Millis, true),
+            positives: data.positives,
+            recovered: data.recovered,
+            deaths: data.deaths,
+            active: data.active
+        }).collect(),
+        reconciliations: active_cases.reconciliations.into_iter().map(|reconciliation| JsonizeReconciliation {
+            attr: reconciliation.attr,
+            aggregate: reconciliation.aggregate,
+            patients: reconciliation.patients
+        }).collect(),
+        last_update: convert_datetime_to_date_and_time(active_cases.last_update)
+    }).unwrap()
+
+}
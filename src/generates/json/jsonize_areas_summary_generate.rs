@@ -0,0 +1,48 @@
+use crate::utils::date_format::{convert_date_to_jst, convert_datetime_to_date_and_time};
+use chrono::SecondsFormat;
+use crate::structs::{
+    area_summary::{AreaSummary, AreasSummary},
+    json::jsonize_area_summary::{JsonizeAreaDailyData, JsonizeAreaSummary, JsonizeAreaWeeklyData, JsonizeAreasSummary},
+    period_summary::Period,
+    time_series::TimeSeries
+};
+
+pub fn jsonize_areas_summary_generate(areas_summary: AreasSummary) -> String {
+
+    serde_json::to_string_pretty(&JsonizeAreasSummary {
+        municipalities: areas_summary.municipalities.into_iter().map(jsonize_area_summary).collect(),
+        regions: areas_summary.regions.into_iter().map(jsonize_area_summary).collect(),
+        health_centers: areas_summary.health_centers.into_iter().map(jsonize_area_summary).collect(),
+        outside: areas_summary.outside,
+        unknown: areas_summary.unknown,
+        last_update: convert_datetime_to_date_and_time(areas_summary.last_update)
+    }).unwrap()
+
+}
+
+fn jsonize_area_summary(area_summary: AreaSummary) -> JsonizeAreaSummary {
+
+    let cumulative: TimeSeries<i64> = area_summary.daily.cumulative();
+
+    JsonizeAreaSummary {
+        name: area_summary.name,
+        code: area_summary.code,
+        population: area_summary.population,
+        total: area_summary.total,
+        total_per_100k: match area_summary.population {
+            0 => None,
+            population => Some((area_summary.total as f64 / population as f64 * 10000000.0).round() / 100.0)
+        },
+        daily: area_summary.daily.iter().zip(cumulative.iter()).map(|((date, sum), (_, cumulative))| JsonizeAreaDailyData {
+            date: convert_date_to_jst(date).to_rfc3339_opts(SecondsFormat::Millis, true),
+            sum: *sum,
+            cumulative: *cumulative
+        }).collect(),
+        weekly: area_summary.weekly.into_iter().map(|(start, sum)| JsonizeAreaWeeklyData {
+            label: Period::Week.label(start),
+            start: start.format("%Y-%m-%d").to_string(),
+            sum
+        }).collect()
+    }
+
+}
@@ -0,0 +1,38 @@
+use crate::utils::date_format::convert_datetime_to_date_and_time;
+use crate::structs::{
+    breakdown::Breakdown,
+    json::jsonize_breakdown::{JsonizeBreakdown, JsonizeBreakdownData, JsonizeMatrix, JsonizeWeek},
+    period_summary::Period
+};
+
+// with_matrixがtrueの場合は、区分×週の表も出力する
+pub fn jsonize_breakdown_generate(breakdown: Breakdown, with_matrix: bool) -> String {
+
+    let week_labels: Vec<String> = breakdown.weeks.iter().map(|week| Period::Week.label(*week)).collect();
+
+    let matrix: Option<JsonizeMatrix> = if with_matrix {
+        Some(JsonizeMatrix {
+            x: week_labels.clone(),
+            y: breakdown.data.iter().map(|data| data.label.clone()).collect(),
+            z: breakdown.data.iter().map(|data| data.weekly.clone()).collect()
+        })
+    } else {
+        None
+    };
+
+    serde_json::to_string_pretty(&JsonizeBreakdown {
+        weeks: breakdown.weeks.iter().zip(week_labels).map(|(week, label)| JsonizeWeek {
+            label,
+            start: week.format("%Y-%m-%d").to_string()
+        }).collect(),
+        data: breakdown.data.into_iter().map(|data| JsonizeBreakdownData {
+            label: data.label,
+            total: data.total,
+            last_seven_days: data.last_seven_days,
+            weekly: data.weekly
+        }).collect(),
+        matrix,
+        last_update: convert_datetime_to_date_and_time(breakdown.last_update)
+    }).unwrap()
+
+}
@@ -0,0 +1,28 @@
+use crate::utils::date_format::convert_datetime_to_date_and_time;
+use chrono::SecondsFormat;
+use crate::structs::{
+    column_series::InspectionsTimeSeries,
+    json::jsonize_column_series::{JsonizeColumnData, JsonizeColumnSeries, JsonizeInspectionsTimeSeries}
+};
+
+pub fn jsonize_inspections_time_series_generate(time_series: InspectionsTimeSeries) -> String {
+
+    let mut columns: Vec<JsonizeColumnSeries> = Vec::new();
+
+    for column in time_series.columns {
+        columns.push(JsonizeColumnSeries {
+            attr: column.attr,
+            data: column.data.into_iter().map(|data| JsonizeColumnData {
+                date: data.date.to_rfc3339_opts(SecondsFormat::Millis, true),
+                value: data.value,
+                delta: data.delta
+            }).collect()
+        });
+    }
+
+    serde_json::to_string_pretty(&JsonizeInspectionsTimeSeries {
+        columns,
+        last_update: convert_datetime_to_date_and_time(time_series.last_update)
+    }).unwrap()
+
+}
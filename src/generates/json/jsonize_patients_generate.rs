@@ -1,11 +1,11 @@
 use chrono::DateTime;
 use crate::Patient;
-use crate::utils::date_format::{convert_datetime_to_date_and_time, convert_utc_to_date};
+use crate::utils::date_format::{convert_date_to_japanese_era, convert_datetime_to_date_and_time, convert_datetime_to_date};
 use crate::utils::merge_age_and_gender::merge_age_and_gender;
-use chrono::{Local, SecondsFormat};
+use chrono::{FixedOffset, SecondsFormat};
 use crate::structs::json::{jsonize_patient::JsonizePatient, patients::Patients};
 
-pub fn jsonize_patients_generate(patients: Vec<Patient>, last_update: DateTime<Local>) -> String {
+pub fn jsonize_patients_generate(patients: Vec<Patient>, last_update: DateTime<FixedOffset>) -> String {
 
     // JSONにシリアライズする形態の構造体を生成
     let mut serialize_patients: Patients = Patients {
@@ -13,38 +13,25 @@ pub fn jsonize_patients_generate(patients: Vec<Patient>, last_update: DateTime<L
         last_update: convert_datetime_to_date_and_time(last_update)
     };
 
-    let patients_length: usize = patients.len();
-
-    for i in 0..patients_length {
+    for patient in &patients {
         serialize_patients.data.push(JsonizePatient {
-            number: patients[i].number,
-            release_date: if patients[i].release_date.is_some() { Some(patients[i].release_date.unwrap().to_rfc3339_opts(SecondsFormat::Millis, true)) } else { None },
-            place: if patients[i].place.is_some() { patients[i].place.clone().unwrap() } else { String::from("") },
-            age_and_gender: if patients[i].age.is_none() && patients[i].gender.is_none() {
-                    String::from("")
-                } else {
-                    let mut age: String = String::from("");
-                    let mut gender: String = String::from(""); 
-
-                    if patients[i].age.is_some() {
-                        age = patients[i].age.clone().unwrap();
-                    }
-
-                    if patients[i].gender.is_some() {
-                        gender = patients[i].gender.clone().unwrap();
-                    }
-                    merge_age_and_gender(&age, &gender)
-                },
-            leave: if patients[i].leave.is_some() {
-                    Some(patients[i].leave.clone().unwrap())
-                } else {
-                    None
-                },
-            date: convert_utc_to_date(patients[i].release_date.unwrap())
+            number: patient.number,
+            release_date: patient.release_date.map(|release_date| release_date.to_rfc3339_opts(SecondsFormat::Millis, true)),
+            release_date_japanese_era: patient.release_date.map(convert_date_to_japanese_era),
+            place: patient.place.clone().unwrap_or_default(),
+            municipality: patient.municipality.name(),
+            municipality_code: patient.municipality.code(),
+            region: patient.municipality.region(),
+            age_and_gender: merge_age_and_gender(patient.age, patient.gender),
+            age: patient.age.label(),
+            gender: patient.gender.label(),
+            leave: patient.leave.clone(),
+            leave_status: patient.leave_status.label(),
+            date: patient.release_date.map(convert_datetime_to_date)
         });
     }
 
     // 陽性者の属性を可読性の高い形でシリアライズ
-    return serde_json::to_string_pretty(&serialize_patients).unwrap();
+    serde_json::to_string_pretty(&serialize_patients).unwrap()
 
 }
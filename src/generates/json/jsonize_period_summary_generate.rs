@@ -0,0 +1,26 @@
+use crate::utils::date_format::convert_datetime_to_date_and_time;
+use crate::structs::{period_summary::PeriodSummary, json::jsonize_period_summary::{JsonizePeriodData, JsonizePeriodSummary}};
+
+pub fn jsonize_period_summary_generate(period_summary: PeriodSummary) -> String {
+
+    let mut data: Vec<JsonizePeriodData> = Vec::new();
+
+    for period_data in period_summary.data {
+        data.push(JsonizePeriodData {
+            label: period_summary.period.label(period_data.start),
+            start: period_data.start.format("%Y-%m-%d").to_string(),
+            end: period_summary.period.end(period_data.start).format("%Y-%m-%d").to_string(),
+            positives: period_data.positives,
+            inspections: period_data.inspections,
+            deaths: period_data.deaths,
+            recoveries: period_data.recoveries,
+            in_progress: period_data.in_progress
+        });
+    }
+
+    serde_json::to_string_pretty(&JsonizePeriodSummary {
+        data,
+        last_update: convert_datetime_to_date_and_time(period_summary.last_update)
+    }).unwrap()
+
+}
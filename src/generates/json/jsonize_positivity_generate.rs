@@ -0,0 +1,27 @@
+use crate::utils::date_format::convert_datetime_to_date_and_time;
+use chrono::SecondsFormat;
+use crate::structs::{positivity::Positivity, json::jsonize_positivity::{JsonizePositivity, JsonizePositivityData}};
+
+pub fn jsonize_positivity_generate(positivity: Positivity) -> String {
+
+    let mut data: Vec<JsonizePositivityData> = Vec::new();
+
+    for positivity_data in positivity.data {
+        data.push(JsonizePositivityData {
+            date: positivity_data.date.to_rfc3339_opts(SecondsFormat::Millis, true),
+            inspections: positivity_data.inspections,
+            positives: positivity_data.positives,
+            rate: positivity_data.rate,
+            weekly_inspections: positivity_data.weekly_inspections,
+            weekly_positives: positivity_data.weekly_positives,
+            weekly_rate: positivity_data.weekly_rate
+        });
+    }
+
+    serde_json::to_string_pretty(&JsonizePositivity {
+        data,
+        lag: positivity.lag,
+        last_update: convert_datetime_to_date_and_time(positivity.last_update)
+    }).unwrap()
+
+}
@@ -0,0 +1,23 @@
+use crate::utils::date_format::{convert_date_to_jst, convert_datetime_to_date_and_time};
+use chrono::SecondsFormat;
+use crate::structs::{
+    reproduction_number::ReproductionNumber,
+    json::jsonize_reproduction_number::{JsonizeReproductionNumber, JsonizeReproductionNumberData}
+};
+
+pub fn jsonize_reproduction_number_generate(reproduction_number: ReproductionNumber) -> String {
+
+    serde_json::to_string_pretty(&JsonizeReproductionNumber {
+        serial_interval_mean: reproduction_number.config.serial_interval_mean,
+        serial_interval_sd: reproduction_number.config.serial_interval_sd,
+        window: reproduction_number.config.window,
+        data: reproduction_number.data.into_iter().map(|data| JsonizeReproductionNumberData {
+            date: convert_date_to_jst(data.date).to_rfc3339_opts(SecondsFormat::Millis, true),
+            mean: data.mean,
+            lower: data.lower,
+            upper: data.upper
+        }).collect(),
+        last_update: convert_datetime_to_date_and_time(reproduction_number.last_update)
+    }).unwrap()
+
+}
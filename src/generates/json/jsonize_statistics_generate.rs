@@ -0,0 +1,27 @@
+use crate::utils::date_format::convert_datetime_to_date_and_time;
+use chrono::SecondsFormat;
+use crate::structs::{statistics::Statistics, json::jsonize_statistics::{JsonizeStatistics, JsonizeStatisticsData}};
+
+pub fn jsonize_statistics_generate(statistics: Statistics) -> String {
+
+    let mut data: Vec<JsonizeStatisticsData> = Vec::new();
+
+    for statistics_data in statistics.data {
+        data.push(JsonizeStatisticsData {
+            date: statistics_data.date.to_rfc3339_opts(SecondsFormat::Millis, true),
+            sum: statistics_data.sum,
+            weekly_sum: statistics_data.weekly_sum,
+            moving_average: statistics_data.moving_average,
+            week_over_week: statistics_data.week_over_week,
+            per_100k: statistics_data.per_100k,
+            weekly_per_100k: statistics_data.weekly_per_100k
+        });
+    }
+
+    serde_json::to_string_pretty(&JsonizeStatistics {
+        data,
+        population: statistics.population,
+        last_update: convert_datetime_to_date_and_time(statistics.last_update)
+    }).unwrap()
+
+}
@@ -0,0 +1,23 @@
+use crate::utils::date_format::{convert_date_to_jst, convert_datetime_to_date_and_time};
+use chrono::SecondsFormat;
+use crate::structs::{trend::Trends, json::jsonize_trend::{JsonizeTrendData, JsonizeTrends}};
+
+pub fn jsonize_trend_generate(trends: Trends) -> String {
+
+    serde_json::to_string_pretty(&JsonizeTrends {
+        window: trends.config.window,
+        increase: trends.config.increase,
+        decrease: trends.config.decrease,
+        data: trends.data.into_iter().map(|data| JsonizeTrendData {
+            date: convert_date_to_jst(data.date).to_rfc3339_opts(SecondsFormat::Millis, true),
+            sum: data.sum,
+            previous_sum: data.previous_sum,
+            ratio: data.ratio,
+            doubling_time: data.doubling_time,
+            halving_time: data.halving_time,
+            trend: data.trend.label()
+        }).collect(),
+        last_update: convert_datetime_to_date_and_time(trends.last_update)
+    }).unwrap()
+
+}
@@ -1,70 +1,211 @@
-use crate::structs::main_summary::{Attribute, MainSummary, MainSummaryChildren};
+use crate::structs::main_summary::{MainSummary, MainSummaryChildren, MainSummaryNode};
 use calamine::{DataType, Range};
-use chrono::{DateTime, Local};
-
-pub fn main_summary_generate(range: Range<DataType>, last_update: DateTime<Local>) -> MainSummary {
-
-    return MainSummary {
-        attr: "検査実施人数".to_string(),
-        value: get_sum(&range, (0, 1)).unwrap(),
-        children: [
-            MainSummaryChildren {
-                attr: "陽性患者数".to_string(),
-                value: get_sum(&range, (0, 2)).unwrap(),
-                children: [
-                    Attribute {
-                        attr: "入院中・入院調整中".to_string(),
-                        value: get_sum(&range, (0, 4)).unwrap()
-                    },
-                    Attribute {
-                        attr: "高度重症病床".to_string(),
-                        value: get_sum(&range, (0, 5)).unwrap()
-                    },
-                    Attribute {
-                        attr: "その他".to_string(),
-                        value: get_sum(&range, (0, 6)).unwrap()
-                    },
-                    Attribute {
-                        attr: "宿泊施設".to_string(),
-                        value: get_sum(&range, (0, 7)).unwrap()
-                    },
-                    Attribute {
-                        attr: "自宅療養".to_string(),
-                        value: get_sum(&range, (0, 8)).unwrap()
-                    },
-                    Attribute {
-                        attr: "死亡".to_string(),
-                        value: get_sum(&range, (0, 9)).unwrap()
-                    },
-                    Attribute {
-                        attr: "退院".to_string(),
-                        value: get_sum(&range, (0, 3)).unwrap()
-                    },
-                    Attribute {
-                        attr: "調整中".to_string(),
-                        value: get_sum(&range, (0, 10)).unwrap()
-                    },
-                ].to_vec()
+use chrono::{DateTime, FixedOffset};
+use crate::structs::{diagnostics::Diagnostics, layout::SheetLayout, time_series::TimeSeries};
+
+// 検査実施人数 → 陽性患者数 → 陽性患者数の内訳 の木構造の、上の2段の見出し
+const ROOT: &str = "検査実施人数";
+const POSITIVE: &str = "陽性患者数";
+
+// 内訳の既知の見出しと、内訳の合計に含めるかどうか（従来の並び順）
+// 高度重症病床とその他は入院中・入院調整中の内数
+const BREAKDOWN: &[(&str, bool)] = &[
+    ("入院中・入院調整中", true),
+    ("高度重症病床", false),
+    ("その他", false),
+    ("宿泊施設", true),
+    ("自宅療養", true),
+    ("死亡", true),
+    ("退院", true),
+    ("調整中", true)
+];
+
+// labelsはワークシートの見出し（見出し行が無い場合はレイアウトの列）で、その並びから木構造を組み立てる
+pub fn main_summary_generate(range: Range<DataType>, labels: &[String], sheet_layout: &SheetLayout, last_update: DateTime<FixedOffset>, sheet: &str, diagnostics: &mut Diagnostics) -> MainSummary {
+
+    let date_column: usize = sheet_layout.column("日付").unwrap_or(0);
+
+    let summary: MainSummaryChildren = match latest_row(&range, date_column) {
+        Some(row) => match main_summary_tree(labels) {
+            Some(tree) => generate_node(&tree, &range, row, sheet, diagnostics),
+            None => {
+                // 見出しが変わって検査実施人数の列が見つからない場合、0のまま出力しないよう問題として記録する
+                diagnostics.push_cell(sheet, &range, (row, sheet_layout.column(ROOT).unwrap_or(0)), &format!("a column labelled {}", ROOT));
+                None
             }
-        ].to_vec(),
-        last_update: last_update
+        },
+        None => {
+            // 日付の読めない行は検査件数の集計で記録しているので、ここでは最新の行が無いことだけを記録する
+            diagnostics.push_cell(sheet, &range, (0, date_column), "a row with a date");
+            None
+        }
+    }.unwrap_or(MainSummaryChildren {
+        attr: ROOT.to_string(),
+        value: 0,
+        children: Vec::new()
+    });
+
+    MainSummary {
+        attr: summary.attr,
+        value: summary.value,
+        children: summary.children,
+        last_update
     }
 
 }
 
-fn get_sum(range: &Range<DataType>, relative_position: (usize, usize)) -> Option<i64> {
+// 日付が最も新しい行の位置を返す（同じ日付の行が複数あれば、上にある行を優先する）
+pub fn latest_row(range: &Range<DataType>, date_column: usize) -> Option<usize> {
 
-    let cell: Option<&DataType> = range.get(relative_position);
+    // 同じ日付では後の値が使われるので、下の行から順に並べる
+    let rows: TimeSeries<Option<usize>> = TimeSeries::from_points(range.rows()
+        .enumerate()
+        .rev()
+        .filter_map(|(i, row)| row.get(date_column).and_then(|cell| cell.as_datetime()).map(|date| (date.date(), i)))
+        .collect());
 
-    match cell {
-        Some(cell) => {
-            if cell.is_float() {
-                Some(cell.get_float().unwrap() as i64)
-            } else {
-                Some(get_sum(range, (relative_position.0 + 1, relative_position.1)).unwrap())
-            }
+    rows.values.last().copied().flatten()
+
+}
+
+// 見出しの並びから木構造を組み立てる
+// 内訳は、既知の見出しを従来の並び順で、続けて未知の見出しを見出しの並び順で並べる
+// 検査実施人数の列が無ければNoneを返し、陽性患者数の列が無ければ内訳ごと出力しない
+fn main_summary_tree(labels: &[String]) -> Option<MainSummaryNode> {
+
+    let position = |attr: &str| labels.iter().position(|label| label == attr);
+
+    let known = BREAKDOWN.iter()
+        .filter_map(|(attr, counted)| position(attr).map(|column| node(attr, column, *counted, true, Vec::new())));
+    let unknown = labels.iter()
+        .enumerate()
+        .filter(|(_, label)| !label.is_empty() && !["日付", ROOT, POSITIVE].contains(&label.as_str()))
+        .filter(|(_, label)| !BREAKDOWN.iter().any(|(attr, _)| attr == label))
+        // 未知の見出しは内数かどうか分からないため、内訳の合計に含めない
+        .map(|(column, label)| node(label, column, false, false, Vec::new()));
+
+    let children: Vec<MainSummaryNode> = match position(POSITIVE) {
+        Some(column) => vec![node(POSITIVE, column, false, true, known.chain(unknown).collect())],
+        None => Vec::new()
+    };
+
+    position(ROOT).map(|column| node(ROOT, column, false, true, children))
+
+}
+
+fn node(attr: &str, column: usize, counted: bool, known: bool, children: Vec<MainSummaryNode>) -> MainSummaryNode {
+    MainSummaryNode {
+        attr: attr.to_string(),
+        column,
+        counted,
+        known,
+        children
+    }
+}
+
+// 木構造の1項目について、最新の行の値と内訳を求める
+// 内訳の合計が値と一致しなければ、注意として記録する
+fn generate_node(node: &MainSummaryNode, range: &Range<DataType>, row: usize, sheet: &str, diagnostics: &mut Diagnostics) -> Option<MainSummaryChildren> {
+
+    let value: i64 = match range.get((row, node.column)).and_then(|cell| cell.get_float()) {
+        Some(value) => value as i64,
+        None if node.known => {
+            diagnostics.push_cell(sheet, range, (row, node.column), "number");
+            0
         },
-        None => None
+        None => {
+            diagnostics.warn_cell(sheet, range, (row, node.column), &format!("number (the column {:?} is not output)", node.attr));
+            return None;
+        }
+    };
+
+    let mut children: Vec<MainSummaryChildren> = Vec::new();
+    let mut counted: Vec<&str> = Vec::new();
+    let mut counted_sum: i64 = 0;
+
+    for child in &node.children {
+        if let Some(generated) = generate_node(child, range, row, sheet, diagnostics) {
+            if child.counted {
+                counted.push(&child.attr);
+                counted_sum += generated.value;
+            }
+            children.push(generated);
+        }
+    }
+
+    if !counted.is_empty() && counted_sum != value {
+        diagnostics.warn_cell(sheet, range, (row, node.column), &format!("the sum of {} ({})", counted.join(", "), counted_sum));
+    }
+
+    Some(MainSummaryChildren {
+        attr: node.attr.clone(),
+        value,
+        children
+    })
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::date_format::jst;
+    use crate::utils::detect_layout::LAYOUTS;
+    use chrono::TimeZone;
+
+    fn labels(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|label| label.to_string()).collect()
+    }
+
+    fn breakdown(tree: &MainSummaryNode) -> Vec<(&str, usize, bool)> {
+        tree.children[0].children.iter().map(|child| (child.attr.as_str(), child.column, child.counted)).collect()
+    }
+
+    #[test]
+    fn orders_known_labels_conventionally_then_unknown_labels() {
+        let tree: MainSummaryNode = main_summary_tree(&labels(&[
+            "日付", "検査実施人数", "陽性患者数", "退院", "新しい区分", "入院中・入院調整中", "高度重症病床", "死亡"
+        ])).unwrap();
+
+        assert_eq!((tree.attr.as_str(), tree.column), ("検査実施人数", 1));
+        assert_eq!((tree.children[0].attr.as_str(), tree.children[0].column), ("陽性患者数", 2));
+        assert_eq!(breakdown(&tree), vec![
+            ("入院中・入院調整中", 5, true),
+            ("高度重症病床", 6, false),
+            ("死亡", 7, true),
+            ("退院", 3, true),
+            ("新しい区分", 4, false)
+        ]);
+    }
+
+    #[test]
+    fn reports_missing_root_column() {
+        let mut range: Range<DataType> = Range::new((0, 0), (0, 10));
+        range.set_value((0, 0), DataType::DateTime(44409.0));
+        range.set_value((0, 1), DataType::Float(100.0));
+
+        let mut diagnostics: Diagnostics = Diagnostics::new();
+        let main_summary: MainSummary = main_summary_generate(
+            range,
+            &labels(&["日付", "検査人数", "陽性患者数"]),
+            LAYOUTS[0].sheet("PCR検査件数").unwrap(),
+            jst().with_ymd_and_hms(2021, 8, 2, 0, 0, 0).unwrap(),
+            "PCR検査件数",
+            &mut diagnostics
+        );
+
+        assert_eq!(main_summary.value, 0);
+        assert_eq!(diagnostics.error_count(), 1);
+        assert_eq!(diagnostics.items[0].cell, "B1");
+        assert!(diagnostics.items[0].expected.contains("検査実施人数"));
+    }
+
+    #[test]
+    fn omits_breakdown_without_positive_column() {
+        let tree: MainSummaryNode = main_summary_tree(&labels(&["日付", "検査実施人数", "退院"])).unwrap();
+
+        assert!(tree.children.is_empty());
+        assert!(main_summary_tree(&labels(&["日付", "陽性患者数"])).is_none());
     }
 
 }
@@ -1,19 +1,47 @@
 use calamine::{Range, DataType};
+use crate::structs::diagnostics::Diagnostics;
 use crate::structs::news::{News, NewsItem};
+use crate::utils::normalize_text::normalize_text;
 
-pub fn news_generate(range: &Range<DataType>) -> News {
+pub fn news_generate(range: &Range<DataType>, sheet: &str, diagnostics: &mut Diagnostics) -> News {
 
     let mut news_items: Vec<NewsItem> = Vec::new();
 
-    for row in range.rows() {
+    for (i, row) in range.rows().enumerate() {
+
+        let date = match row[0].as_datetime() {
+            Some(date) => date,
+            None => {
+                diagnostics.push_cell(sheet, range, (i, 0), "date");
+                continue;
+            }
+        };
+
+        let text = match row.get(1).and_then(|cell| cell.get_string()) {
+            Some(text) => text,
+            None => {
+                diagnostics.push_cell(sheet, range, (i, 1), "text");
+                continue;
+            }
+        };
+
+        let url = match row.get(2).and_then(|cell| cell.get_string()) {
+            Some(url) => url,
+            None => {
+                diagnostics.push_cell(sheet, range, (i, 2), "URL");
+                continue;
+            }
+        };
+
         news_items.push(NewsItem {
-            date: row[0].as_datetime().unwrap().format("%Y/%m/%d").to_string(),
-            text: row[1].get_string().unwrap().to_string(),
-            url: row[2].get_string().unwrap().to_string()
+            date: date.format("%Y/%m/%d").to_string(),
+            text: normalize_text(text),
+            // URLはNFKCで別の文字列に変わり得るため、前後の空白のみを取り除く
+            url: url.trim().to_string()
         });
     }
 
-    return News {
-        news_items: news_items
-    };
+    News {
+        news_items
+    }
 }
@@ -1,43 +1,255 @@
 use calamine::DataType;
-use crate::structs::patient::Patient;
-use crate::utils::date_format::{convert_japanese_era_to_utc};
+use crate::structs::{age_group::AgeGroup, diagnostics::Diagnostics, gender::Gender, layout::{SheetLayout, Worksheet}, leave_status::LeaveStatus, municipality::Place, patient::Patient};
+use crate::utils::cell_address::convert_position_to_a1;
+use crate::utils::date_format::{convert_japanese_date_to_jst};
+use crate::utils::normalize_municipality::{KYOTO, normalize_municipality};
+use crate::utils::normalize_text::normalize_text;
 
-use calamine::{Range};
+use regex::Regex;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::LazyLock;
 
-pub fn patients_generate(range: Range<DataType>) -> Vec<Patient> {
+// 陽性者の属性シート（複数のシートに分かれている場合は、その全て）を順番に読み込む
+pub fn patients_generate(worksheets: &[Worksheet], sheet_layout: &SheetLayout, diagnostics: &mut Diagnostics) -> Vec<Patient> {
 
     let mut patients: Vec<Patient> = Vec::new();
+    // 通し番号と、その番号が書かれていたシートと行
+    let mut numbers: Vec<(i32, usize, usize)> = Vec::new();
+
+    /*
+        陽性者の属性シートの列
+        通し番号、公表日、年齢、性別、居住地、退院
+    */
+    let number_column: Option<usize> = sheet_layout.column("通し番号");
+    let release_date_column: Option<usize> = sheet_layout.column("公表日");
+    let age_column: Option<usize> = sheet_layout.column("年齢");
+    let gender_column: Option<usize> = sheet_layout.column("性別");
+    let place_column: Option<usize> = sheet_layout.column("居住地");
+    let leave_column: Option<usize> = sheet_layout.column("退院");
+
+    for (sheet_index, (sheet, range)) in worksheets.iter().enumerate() {
+
+        // 陽性者を全て取得
+        for (i, row) in range.rows().enumerate().rev() {
+
+            // 解釈には正規化した値を使い、居住地と退院は元の値も残しておく
+            let number_str: String = normalize_text(&get_text(row, number_column));
+            let release_date_str: String = normalize_text(&get_text(row, release_date_column));
+            let age_str: String = normalize_text(&get_text(row, age_column));
+            let gender_str: String = normalize_text(&get_text(row, gender_column));
+            let place_str: String = normalize_text(&get_text(row, place_column));
+            let leave_str: String = normalize_text(&get_text(row, leave_column));
+            let raw_place_str: String = get_text(row, place_column);
+            let raw_leave_str: String = get_text(row, leave_column);
+
+            // 通し番号を符号付き32bit整数になるようにパース
+            let number: i32 = match parse_patient_number(number_column.and_then(|column| row.get(column)), &number_str) {
+                Some(number) => number,
+                None => {
+                    diagnostics.push_cell(sheet, range, (i, number_column.unwrap_or(0)), "patient number");
+                    continue;
+                }
+            };
+
+            // 公表日が空の陽性者は、日付不明として扱った上で報告する
+            let release_date = if release_date_str.is_empty() {
+                diagnostics.warn_cell(sheet, range, (i, release_date_column.unwrap_or(0)), "release date (the patient is counted as undated)");
+                None
+            } else {
+                match convert_japanese_date_to_jst(&release_date_str) {
+                    Ok(release_date) => Some(release_date),
+                    Err(_) => {
+                        diagnostics.push_cell(sheet, range, (i, release_date_column.unwrap_or(0)), "date");
+                        continue;
+                    }
+                }
+            };
+
+            // 年代と性別は解釈できなかった場合も公開は止めず、警告として記録した上で不明として扱う
+            let age: AgeGroup = AgeGroup::parse(&age_str).unwrap_or_else(|| {
+                diagnostics.warn_cell(sheet, range, (i, age_column.unwrap_or(0)), "age group (the patient is counted as unknown)");
+                AgeGroup::Unknown
+            });
+            let gender: Gender = Gender::parse(&gender_str).unwrap_or_else(|| {
+                diagnostics.warn_cell(sheet, range, (i, gender_column.unwrap_or(0)), "gender (the patient is counted as unknown)");
+                Gender::Unknown
+            });
+
+            // 居住地は元の値を残した上で、正規化した市区町村を別に持つ
+            // 正規化できない居住地は公開を止めず、警告として記録した上で不明として扱う
+            let municipality: Place = normalize_municipality(&place_str, &KYOTO).unwrap_or_else(|| {
+                diagnostics.warn_cell(sheet, range, (i, place_column.unwrap_or(0)), "municipality (the patient is counted as unknown)");
+                Place::Unknown
+            });
+
+            // 退院欄も元の値を残した上で、状況を別に持つ
+            // 対応表に無い表記は公開を止めず、警告として記録した上で不明として扱う
+            let leave_status: LeaveStatus = LeaveStatus::parse(&leave_str).unwrap_or_else(|| {
+                diagnostics.warn_cell(sheet, range, (i, leave_column.unwrap_or(0)), "leave status (the patient is counted as unknown)");
+                LeaveStatus::Unknown
+            });
+
+            numbers.push((number, sheet_index, i));
+
+            // 生成した構造体をpatientsに追加する際、空チェックを行う
+            patients.push(Patient {
+                number,
+                release_date,
+                age,
+                gender,
+                place: if place_str.is_empty() { None } else { Some(raw_place_str) },
+                municipality,
+                leave: if leave_str.is_empty() { None } else { Some(raw_leave_str) },
+                leave_status
+            });
+        }
+
+    }
+
+    check_numbers(&numbers, number_column.unwrap_or(0), worksheets, diagnostics);
+
+    patients
+}
+
+// レイアウトに無い列は空として扱う
+fn get_text(row: &[DataType], column: Option<usize>) -> String {
+    column.and_then(|column| row.get(column)).map(|cell| cell.to_string()).unwrap_or_default()
+}
+
+// 通し番号の表記（例: 12例目、No.12、第12）
+static PATIENT_NUMBER_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?i:No\.?|#|第)?\s*([0-9][0-9,]*)(?:\.0+)?\s*(?:例目|例)?$").unwrap()
+});
+
+// 通し番号の表記揺れ（例: 12例目、1,234例目、No.12、12.0）を吸収してパースする
+fn parse_patient_number(cell: Option<&DataType>, number_str: &str) -> Option<i32> {
+
+    // 数値として保存されているセルは、整数であればそのまま使う
+    if let Some(number) = cell.and_then(|cell| cell.get_int()) {
+        return i32::try_from(number).ok();
+    }
+
+    if let Some(number) = cell.and_then(|cell| cell.get_float()) {
+        return if number.fract() == 0.0 { i32::try_from(number as i64).ok() } else { None };
+    }
+
+    let matches = PATIENT_NUMBER_RE.captures(number_str)?;
+
+    matches[1].replace(',', "").parse().ok()
+
+}
+
+// 通し番号の重複、欠番、順序の乱れを問題として記録する
+fn check_numbers(numbers: &[(i32, usize, usize)], column: usize, worksheets: &[Worksheet], diagnostics: &mut Diagnostics) {
+
+    let mut first_positions: HashMap<i32, (usize, usize)> = HashMap::new();
+    let mut last_number: Option<(i32, usize)> = None;
+
+    // 行はシートごとに下から順に読んでいるので、番号は同じシートの中で昇順に並んでいるはず
+    // シートの並びと番号の範囲は一致するとは限らないため、順序はシートをまたいで比べない
+    for &(number, sheet_index, row) in numbers {
+        let (sheet, range) = &worksheets[sheet_index];
+
+        if let Some((first_sheet_index, first_row)) = first_positions.get(&number) {
+            let (first_sheet, first_range) = &worksheets[*first_sheet_index];
+            let start: (u32, u32) = first_range.start().unwrap_or((0, 0));
+
+            diagnostics.push_cell(sheet, range, (row, column), &format!(
+                "unique patient number (also at {}!{})", first_sheet, convert_position_to_a1((start.0 + *first_row as u32, start.1 + column as u32))
+            ));
+        } else {
+            first_positions.insert(number, (sheet_index, row));
+        }
+
+        if let Some((last_number, last_sheet_index)) = last_number {
+            if last_sheet_index == sheet_index && number < last_number {
+                diagnostics.warn_cell(sheet, range, (row, column), &format!(
+                    "patient number greater than {}", last_number
+                ));
+            }
+        }
+
+        last_number = Some((number, sheet_index));
+    }
+
+    let mut sorted_numbers: Vec<(i32, (usize, usize))> = first_positions.into_iter().collect();
+    sorted_numbers.sort();
+
+    for pair in sorted_numbers.windows(2) {
+        let ((previous, _), (number, (sheet_index, row))) = (pair[0], pair[1]);
+        let (sheet, range) = &worksheets[sheet_index];
+
+        if number > previous + 1 {
+            diagnostics.warn_cell(sheet, range, (row, column), &format!(
+                "patient number {} (numbers {} to {} are missing)", previous + 1, previous + 1, number - 1
+            ));
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use calamine::Range;
+    use crate::structs::diagnostics::Severity;
+
+    fn worksheets(count: usize) -> Vec<Worksheet> {
+        (0..count).map(|i| (format!("Sheet{}", i + 1), Range::new((0, 0), (9, 0)))).collect()
+    }
+
+    fn check(numbers: &[(i32, usize, usize)], sheet_count: usize) -> Diagnostics {
+        let mut diagnostics: Diagnostics = Diagnostics::new();
+        check_numbers(numbers, 0, &worksheets(sheet_count), &mut diagnostics);
+        diagnostics
+    }
+
+    #[test]
+    fn accepts_consecutive_numbers() {
+        assert!(check(&[(1, 0, 2), (2, 0, 1), (3, 0, 0)], 1).is_empty());
+    }
+
+    #[test]
+    fn reports_duplicates_as_errors() {
+        let diagnostics: Diagnostics = check(&[(1, 0, 2), (2, 0, 1), (2, 0, 0)], 1);
+
+        assert_eq!(diagnostics.error_count(), 1);
+        assert_eq!(diagnostics.items[0].cell, "A1");
+        assert!(diagnostics.items[0].expected.contains("Sheet1!A2"));
+    }
+
+    #[test]
+    fn reports_gaps_as_warnings() {
+        let diagnostics: Diagnostics = check(&[(1, 0, 1), (4, 0, 0)], 1);
+
+        assert_eq!(diagnostics.error_count(), 0);
+        assert_eq!(diagnostics.items.len(), 1);
+        assert!(diagnostics.items[0].expected.contains("numbers 2 to 3 are missing"));
+    }
+
+    #[test]
+    fn reports_disorder_within_sheet() {
+        let diagnostics: Diagnostics = check(&[(1, 0, 2), (3, 0, 1), (2, 0, 0)], 1);
+
+        assert_eq!(diagnostics.items.len(), 1);
+        assert!(diagnostics.items[0].severity == Severity::Warning);
+        assert!(diagnostics.items[0].expected.contains("greater than 3"));
+    }
+
+    #[test]
+    fn ignores_order_across_split_sheets() {
+        // 新しい番号のシートが先に並んでいても、番号の抜けや重複が無ければ問題にしない
+        assert!(check(&[(4, 0, 1), (5, 0, 0), (1, 1, 2), (2, 1, 1), (3, 1, 0)], 2).is_empty());
+    }
+
+    #[test]
+    fn checks_gaps_and_duplicates_across_split_sheets() {
+        let diagnostics: Diagnostics = check(&[(5, 0, 1), (6, 0, 0), (1, 1, 2), (2, 1, 1), (6, 1, 0)], 2);
+
+        assert_eq!(diagnostics.error_count(), 1);
+        assert!(diagnostics.items.iter().any(|item| item.expected.contains("numbers 3 to 4 are missing")));
+    }
 
-    // 陽性者を全て取得
-    for row in range.rows().rev() {
-        // 通し番号を符号なし32bit整数になるようにパース
-        let number: i32 = row[0].to_string().replace("例目", "").parse().unwrap();
-        let release_date_str: String = row[1].to_string();
-        let age_str: String = row[2].to_string();
-        let gender_str: String = row[3].to_string();
-        let place_str: String = row[4].to_string();
-        let leave_str: String = row[5].to_string();
-
-        /*
-            陽性者の属性シートのデータ構造
-            1列目: 通し番号
-            2列目: 公表日
-            3列目: 年齢
-            4列目: 性別
-            5列目: 居住地
-            6列目: 退院
-        */
-
-        // 生成した構造体をpatientsに追加する際、空チェックとハイフンチェックを行う
-        patients.push(Patient {
-            number: number,
-            release_date: if release_date_str == "" { None } else { Some(convert_japanese_era_to_utc(&release_date_str).unwrap()) },
-            age: if age_str == "" || gender_str == "-" { None } else { Some(age_str) },
-            gender: if gender_str == "" || gender_str == "-" { None } else { Some(gender_str) },
-            place: if place_str == "" { None } else { Some(place_str) },
-            leave: if leave_str == "" { None } else { Some(leave_str) }
-        });
-    }
-
-    return patients;
 }
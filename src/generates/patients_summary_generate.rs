@@ -1,48 +1,9 @@
-use crate::utils::date_format::IncorrectFormatError;
-use chrono::{DateTime, Duration, Local, Utc};
-use crate::structs::{summary::Summary, sumdata::SumData};
+use chrono::{DateTime, FixedOffset};
+use crate::structs::{summary::Summary, time_series::TimeSeries};
 
-pub fn patients_summary_generate(datetimes: Vec<DateTime<Utc>>, min: DateTime<Utc>, max: DateTime<Utc>, last_update: DateTime<Local>) -> Result<Summary, IncorrectFormatError> {
+// 公表日ごとの陽性者数を、最初の公表日から最後の公表日まで1日ずつ数える
+pub fn patients_summary_generate(datetimes: &[DateTime<FixedOffset>], last_update: DateTime<FixedOffset>) -> Summary {
 
-    if !(min > max) {
-
-        let mut min: DateTime<Utc> = min.clone();
-
-        let mut datetimes: Vec<DateTime<Utc>> = datetimes.clone();
-        datetimes.sort();
-
-        let mut datetimes_index: usize = 0;
-        let mut summary_index: usize = 0;
-
-        let mut summary: Summary = Summary {
-            data: Vec::new(),
-            last_update: last_update
-        };
-    
-        while max >= min {
-
-            summary.data.push(SumData {
-                date: min,
-                sum: 0
-            });
-
-            while datetimes.len() > datetimes_index {
-                if datetimes[datetimes_index] == summary.data[summary_index].date {
-                    summary.data[summary_index].sum += 1;
-                    datetimes_index += 1;
-                } else {
-                    break;
-                }
-            }
-
-            summary_index += 1;
-            min = min + Duration::days(1);
-        }
-
-        return Ok(summary);
-
-    }
-
-    return Err(IncorrectFormatError {});
+    TimeSeries::from_events(datetimes).to_summary(last_update)
 
 }
@@ -0,0 +1,132 @@
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use crate::structs::{
+    column_series::InspectionsTimeSeries,
+    period_summary::{Period, PeriodData, PeriodSummary},
+    summary::Summary,
+    time_series::TimeSeries
+};
+use crate::utils::date_format::convert_jst_to_date;
+
+// 陽性患者数と検査実施人数、死亡と退院の日ごとの件数を、週または月ごとに合計する
+pub fn period_summary_generate(period: Period, patients_summary: &Summary, inspections_summary: &Summary, inspections_time_series: &InspectionsTimeSeries) -> PeriodSummary {
+
+    let series: [TimeSeries<i64>; 4] = [
+        TimeSeries::from_summary(patients_summary),
+        TimeSeries::from_summary(inspections_summary),
+        column_daily(inspections_time_series, "死亡"),
+        column_daily(inspections_time_series, "退院")
+    ];
+
+    // 期間の初日ごとに、4つの件数の合計を集める
+    let mut sums: BTreeMap<NaiveDate, [i64; 4]> = BTreeMap::new();
+
+    for (i, daily) in series.iter().enumerate() {
+        let resampled: Vec<(NaiveDate, i64)> = match period {
+            Period::Week => daily.weekly(),
+            Period::Month => daily.monthly()
+        };
+
+        for (start, sum) in resampled {
+            sums.entry(start).or_insert([0; 4])[i] += sum;
+        }
+    }
+
+    // 最終更新日より後に終わる期間は、まだ全ての日が揃っていない
+    let last_update_date: NaiveDate = convert_jst_to_date(patients_summary.last_update);
+
+    PeriodSummary {
+        period,
+        data: sums.into_iter().map(|(start, sums)| PeriodData {
+            start,
+            positives: sums[0],
+            inspections: sums[1],
+            deaths: sums[2],
+            recoveries: sums[3],
+            in_progress: period.end(start) > last_update_date
+        }).collect(),
+        last_update: patients_summary.last_update
+    }
+
+}
+
+// PCR検査件数ワークシートの累計の列から、日ごとの件数を求める
+// 日ごとの件数は直前に値のある日の累計との差とし、最初の日はそれまでの累計を含めないよう0とする
+fn column_daily(inspections_time_series: &InspectionsTimeSeries, attr: &str) -> TimeSeries<i64> {
+
+    let mut last_value: Option<i64> = None;
+    let points: Vec<(NaiveDate, i64)> = inspections_time_series.columns.iter()
+        .filter(|column| column.attr == attr)
+        .flat_map(|column| column.data.iter())
+        .map(|data| {
+            let daily: i64 = last_value.map_or(0, |last_value| data.value - last_value);
+            last_value = Some(data.value);
+            (convert_jst_to_date(data.date), daily)
+        })
+        .collect();
+
+    TimeSeries::from_points(points).fill(0)
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::structs::column_series::{ColumnData, ColumnSeries};
+    use crate::structs::sumdata::SumData;
+    use crate::utils::date_format::{convert_date_to_jst, jst};
+    use chrono::{DateTime, FixedOffset, TimeZone};
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn summary(data: &[(NaiveDate, i64)], last_update: DateTime<FixedOffset>) -> Summary {
+        Summary {
+            data: data.iter().map(|(date, sum)| SumData { date: convert_date_to_jst(*date), sum: *sum, note: None }).collect(),
+            undated: None,
+            last_update
+        }
+    }
+
+    fn time_series(attr: &str, data: &[(NaiveDate, i64)], last_update: DateTime<FixedOffset>) -> InspectionsTimeSeries {
+        InspectionsTimeSeries {
+            columns: vec![ColumnSeries {
+                attr: attr.to_string(),
+                data: data.iter().map(|(date, value)| ColumnData { date: convert_date_to_jst(*date), value: *value, delta: None }).collect()
+            }],
+            last_update
+        }
+    }
+
+    #[test]
+    fn cumulative_columns_start_from_zero() {
+        // 2021年8月2日は月曜日で、記録の最初の日の累計100は、その週の件数に含めない
+        let last_update: DateTime<FixedOffset> = jst().with_ymd_and_hms(2021, 8, 15, 0, 0, 0).unwrap();
+        let deaths: InspectionsTimeSeries = time_series("死亡", &[(ymd(2021, 8, 2), 100), (ymd(2021, 8, 3), 101), (ymd(2021, 8, 5), 104), (ymd(2021, 8, 9), 105)], last_update);
+        let empty: Summary = summary(&[], last_update);
+
+        let period_summary: PeriodSummary = period_summary_generate(Period::Week, &empty, &empty, &deaths);
+
+        assert_eq!(period_summary.data.iter().map(|data| (data.start, data.deaths)).collect::<Vec<(NaiveDate, i64)>>(), vec![
+            (ymd(2021, 8, 2), 4),
+            (ymd(2021, 8, 9), 1)
+        ]);
+    }
+
+    #[test]
+    fn flags_periods_ending_after_last_update() {
+        let last_update: DateTime<FixedOffset> = jst().with_ymd_and_hms(2021, 8, 8, 20, 0, 0).unwrap();
+        let patients: Summary = summary(&[(ymd(2021, 8, 7), 1), (ymd(2021, 8, 8), 1), (ymd(2021, 8, 9), 1)], last_update);
+        let empty: Summary = summary(&[], last_update);
+        let inspections_time_series: InspectionsTimeSeries = time_series("死亡", &[], last_update);
+
+        let weekly: PeriodSummary = period_summary_generate(Period::Week, &patients, &empty, &inspections_time_series);
+        let monthly: PeriodSummary = period_summary_generate(Period::Month, &patients, &empty, &inspections_time_series);
+
+        assert_eq!(weekly.data.iter().map(|data| data.in_progress).collect::<Vec<bool>>(), vec![false, true]);
+        assert_eq!(monthly.data.iter().map(|data| data.in_progress).collect::<Vec<bool>>(), vec![true]);
+    }
+
+}
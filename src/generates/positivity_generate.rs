@@ -0,0 +1,138 @@
+use chrono::Duration;
+use crate::structs::{positivity::{Positivity, PositivityData}, summary::Summary, time_series::TimeSeries};
+use crate::utils::date_format::convert_date_to_jst;
+
+// 陽性率を求める期間の日数
+const WINDOW: usize = 7;
+
+// 日ごとの陽性患者数と、lag日前の検査実施人数を突き合わせて陽性率を求める
+// 陽性率は百分率で、小数第1位までに丸める
+pub fn positivity_generate(inspections_summary: &Summary, patients_summary: &Summary, lag: i64) -> Positivity {
+
+    let inspections: TimeSeries<i64> = TimeSeries::from_summary(inspections_summary);
+    let positives: TimeSeries<i64> = TimeSeries::from_summary(patients_summary);
+
+    // 検査実施人数と、lag日後の陽性患者数の組
+    // 陽性患者数の集計期間の外の日は、陽性者がいなかった日とみなす
+    let pairs: TimeSeries<(i64, i64)> = TimeSeries::from_points(inspections.iter()
+        .map(|(date, sum)| (date, (*sum, positives.get(date + Duration::days(lag)).copied().unwrap_or(0))))
+        .collect()).fill((0, 0));
+    let weekly: TimeSeries<Option<(i64, i64)>> = pairs.window(WINDOW, |window| (
+        window.iter().map(|(inspections, _)| inspections).sum(),
+        window.iter().map(|(_, positives)| positives).sum()
+    ));
+
+    let mut positivity: Positivity = Positivity {
+        data: Vec::new(),
+        lag,
+        last_update: inspections_summary.last_update
+    };
+
+    for ((date, (inspections, positives)), (_, weekly)) in pairs.iter().zip(weekly.iter()) {
+        positivity.data.push(PositivityData {
+            date: convert_date_to_jst(date + Duration::days(lag)),
+            inspections: *inspections,
+            positives: *positives,
+            rate: rate(*positives, *inspections),
+            weekly_inspections: weekly.map(|(inspections, _)| inspections),
+            weekly_positives: weekly.map(|(_, positives)| positives),
+            weekly_rate: weekly.and_then(|(inspections, positives)| rate(positives, inspections))
+        });
+    }
+
+    positivity
+
+}
+
+fn rate(positives: i64, inspections: i64) -> Option<f64> {
+
+    if inspections <= 0 {
+        return None;
+    }
+
+    Some((positives as f64 / inspections as f64 * 1000.0).round() / 10.0)
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::structs::sumdata::SumData;
+    use crate::utils::date_format::jst;
+    use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+
+    fn ymd(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2021, 8, day).unwrap()
+    }
+
+    // 8月の日と件数の組から作る（組の無い日は、Summaryに含めない）
+    fn summary(data: &[(u32, i64)]) -> Summary {
+        Summary {
+            data: data.iter().map(|(day, sum)| SumData { date: convert_date_to_jst(ymd(*day)), sum: *sum, note: None }).collect(),
+            undated: None,
+            last_update: jst().with_ymd_and_hms(2021, 8, 31, 0, 0, 0).unwrap()
+        }
+    }
+
+    fn date(day: u32) -> DateTime<FixedOffset> {
+        convert_date_to_jst(ymd(day))
+    }
+
+    #[test]
+    fn computes_daily_and_weekly_rates() {
+        let inspections: Summary = summary(&(1..=8).map(|day| (day, 100)).collect::<Vec<(u32, i64)>>());
+        let positives: Summary = summary(&(1..=8).map(|day| (day, day as i64)).collect::<Vec<(u32, i64)>>());
+
+        let positivity: Positivity = positivity_generate(&inspections, &positives, 0);
+
+        assert_eq!(positivity.data.len(), 8);
+        assert_eq!(positivity.data[2].rate, Some(3.0));
+        assert_eq!(positivity.data[5].weekly_rate, None);
+        assert_eq!(positivity.data[6].weekly_inspections, Some(700));
+        assert_eq!(positivity.data[6].weekly_positives, Some(28));
+        assert_eq!(positivity.data[6].weekly_rate, Some(4.0));
+        assert_eq!(positivity.data[7].weekly_positives, Some(35));
+        assert_eq!(positivity.data[7].weekly_rate, Some(5.0));
+    }
+
+    #[test]
+    fn matches_positives_with_lagged_inspections() {
+        let positivity: Positivity = positivity_generate(&summary(&[(1, 200), (2, 100)]), &summary(&[(2, 10), (3, 30)]), 1);
+
+        assert_eq!(positivity.data[0].date, date(2));
+        assert_eq!((positivity.data[0].inspections, positivity.data[0].positives), (200, 10));
+        assert_eq!(positivity.data[0].rate, Some(5.0));
+        assert_eq!(positivity.data[1].rate, Some(30.0));
+    }
+
+    #[test]
+    fn leaves_rate_null_without_inspections() {
+        let positivity: Positivity = positivity_generate(&summary(&[(1, 0), (2, 100)]), &summary(&[(1, 3), (2, 3)]), 0);
+
+        assert_eq!(positivity.data[0].rate, None);
+        assert_eq!(positivity.data[1].rate, Some(3.0));
+        assert!(positivity.data.iter().filter_map(|data| data.rate).all(|rate| rate.is_finite()));
+
+        let positivity: Positivity = positivity_generate(&summary(&(1..=7).map(|day| (day, 0)).collect::<Vec<(u32, i64)>>()), &summary(&[(7, 1)]), 0);
+
+        assert_eq!(positivity.data[6].weekly_inspections, Some(0));
+        assert_eq!(positivity.data[6].weekly_rate, None);
+    }
+
+    #[test]
+    fn counts_missing_day_in_window_as_zero() {
+        // 8月4日の検査実施人数が無い
+        let inspections: Summary = summary(&[(1, 100), (2, 100), (3, 100), (5, 100), (6, 100), (7, 100)]);
+        let positives: Summary = summary(&[(1, 6), (4, 6), (7, 6)]);
+
+        let positivity: Positivity = positivity_generate(&inspections, &positives, 0);
+
+        assert_eq!(positivity.data[3].date, date(4));
+        assert_eq!((positivity.data[3].inspections, positivity.data[3].rate), (0, None));
+        assert_eq!(positivity.data[6].weekly_inspections, Some(600));
+        assert_eq!(positivity.data[6].weekly_positives, Some(18));
+        assert_eq!(positivity.data[6].weekly_rate, Some(3.0));
+    }
+
+}
@@ -0,0 +1,157 @@
+use crate::structs::{
+    reproduction_number::{ReproductionNumber, ReproductionNumberConfig, ReproductionNumberData},
+    summary::Summary,
+    time_series::TimeSeries
+};
+use crate::utils::gamma::{gamma_cdf, gamma_quantile};
+
+// 離散化した発症間隔の分布を打ち切る累積確率
+const SERIAL_INTERVAL_COVERAGE: f64 = 0.999;
+
+// 日ごとの陽性者数から、Cori et al. (2013) の方法で実効再生産数を推定する
+// 期間内の陽性者数の合計と感染力の合計から、ガンマ分布の事後分布を求める
+pub fn reproduction_number_generate(patients_summary: &Summary, config: ReproductionNumberConfig) -> ReproductionNumber {
+
+    let incidence: TimeSeries<i64> = TimeSeries::from_summary(patients_summary);
+    let weights: Vec<f64> = serial_interval_weights(config.serial_interval_mean, config.serial_interval_sd);
+
+    // 各日の感染力（それまでの陽性者数を発症間隔の分布で重み付けした和）
+    let infectiousness: Vec<f64> = (0..incidence.values.len())
+        .map(|t| (1..weights.len().min(t + 1))
+            .map(|k| incidence.values[t - k] as f64 * weights[k])
+            .sum())
+        .collect();
+
+    let mut reproduction_number: ReproductionNumber = ReproductionNumber {
+        config,
+        data: Vec::new(),
+        last_update: patients_summary.last_update
+    };
+
+    for (t, (date, _)) in incidence.iter().enumerate() {
+        // 最初の日は感染力が無いため、2日目から期間を数える
+        if config.window == 0 || t < config.window {
+            continue;
+        }
+
+        let range = t + 1 - config.window..=t;
+        let cases: f64 = incidence.values[range.clone()].iter().sum::<i64>() as f64;
+        let total_infectiousness: f64 = infectiousness[range].iter().sum();
+
+        if total_infectiousness <= 0.0 {
+            continue;
+        }
+
+        let shape: f64 = config.prior_shape + cases;
+        let scale: f64 = 1.0 / (1.0 / config.prior_scale + total_infectiousness);
+
+        reproduction_number.data.push(ReproductionNumberData {
+            date,
+            mean: round(shape * scale),
+            lower: round(gamma_quantile(shape, scale, 0.025)),
+            upper: round(gamma_quantile(shape, scale, 0.975))
+        });
+    }
+
+    reproduction_number
+
+}
+
+// 発症間隔のガンマ分布を1日ごとに離散化する（0日目は0とし、合計が1になるように正規化する）
+fn serial_interval_weights(mean: f64, sd: f64) -> Vec<f64> {
+
+    let shape: f64 = (mean / sd).powi(2);
+    let scale: f64 = sd * sd / mean;
+
+    let mut weights: Vec<f64> = vec![0.0];
+    let mut k: f64 = 1.0;
+
+    while gamma_cdf(shape, scale, k - 0.5) < SERIAL_INTERVAL_COVERAGE {
+        weights.push(gamma_cdf(shape, scale, k + 0.5) - gamma_cdf(shape, scale, k - 0.5));
+        k += 1.0;
+    }
+
+    let total: f64 = weights.iter().sum();
+
+    weights.iter().map(|weight| weight / total).collect()
+
+}
+
+// 小数第2位までに丸める
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::date_format::jst;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn config(window: usize) -> ReproductionNumberConfig {
+        ReproductionNumberConfig {
+            serial_interval_mean: 4.8,
+            serial_interval_sd: 2.3,
+            prior_shape: 1.0,
+            prior_scale: 5.0,
+            window
+        }
+    }
+
+    fn summary(values: Vec<i64>) -> Summary {
+        TimeSeries { start: NaiveDate::from_ymd_opt(2021, 8, 1), values }
+            .to_summary(jst().with_ymd_and_hms(2021, 12, 1, 0, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn serial_interval_weights_are_normalized() {
+        let weights: Vec<f64> = serial_interval_weights(4.8, 2.3);
+        let mean: f64 = weights.iter().enumerate().map(|(k, weight)| k as f64 * weight).sum();
+
+        assert_eq!(weights[0], 0.0);
+        assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!((mean - 4.8).abs() < 0.1);
+    }
+
+    #[test]
+    fn constant_incidence_gives_one() {
+        let reproduction_number: ReproductionNumber = reproduction_number_generate(&summary(vec![100; 60]), config(7));
+        let last: &ReproductionNumberData = reproduction_number.data.last().unwrap();
+
+        assert!((last.mean - 1.0).abs() <= 0.01);
+        assert!(last.lower < last.mean && last.mean < last.upper);
+        assert!(last.lower > 0.9 && last.upper < 1.1);
+    }
+
+    #[test]
+    fn exponential_growth_matches_euler_lotka() {
+        // 1日あたり10%の増加では、R = 1 / Σ w(k) exp(-rk)
+        let growth: f64 = 0.1;
+        let values: Vec<i64> = (0..60).map(|t| (1000.0 * (growth * t as f64).exp()) as i64).collect();
+        let expected: f64 = 1.0 / serial_interval_weights(4.8, 2.3).iter()
+            .enumerate()
+            .map(|(k, weight)| weight * (-growth * k as f64).exp())
+            .sum::<f64>();
+
+        let reproduction_number: ReproductionNumber = reproduction_number_generate(&summary(values), config(7));
+
+        assert!((reproduction_number.data.last().unwrap().mean - expected).abs() <= 0.01);
+    }
+
+    #[test]
+    fn posterior_follows_prior_and_counts() {
+        // 感染力が0の日しか無い期間は推定しない
+        let reproduction_number: ReproductionNumber = reproduction_number_generate(&summary(vec![0, 0, 0, 5]), config(2));
+
+        assert!(reproduction_number.data.is_empty());
+
+        // 陽性者が0の期間の事後平均は、事前分布の形状母数を感染力で割った値に近づく
+        let mut values: Vec<i64> = vec![100; 30];
+        values.extend(vec![0; 2]);
+        let reproduction_number: ReproductionNumber = reproduction_number_generate(&summary(values), config(1));
+
+        assert!(reproduction_number.data.last().unwrap().mean < 0.02);
+    }
+
+}
@@ -0,0 +1,107 @@
+use chrono::Duration;
+use crate::structs::{statistics::{Statistics, StatisticsData}, summary::Summary, time_series::TimeSeries};
+use crate::utils::date_format::convert_date_to_jst;
+
+// 移動平均と合計を求める期間の日数
+const WINDOW: usize = 7;
+
+// 日ごとの件数から、7日間の合計と移動平均、前週比、人口10万人あたりの件数を求める
+pub fn statistics_generate(summary: &Summary, population: u64) -> Statistics {
+
+    let sums: TimeSeries<i64> = TimeSeries::from_summary(summary);
+    let weekly_sums: TimeSeries<Option<i64>> = sums.window_sum(WINDOW);
+
+    let mut statistics: Statistics = Statistics {
+        data: Vec::new(),
+        population,
+        last_update: summary.last_update
+    };
+
+    for ((date, sum), (_, weekly_sum)) in sums.iter().zip(weekly_sums.iter()) {
+
+        let week_over_week: Option<f64> = sums.get(date - Duration::days(WINDOW as i64))
+            .filter(|last_week| **last_week > 0)
+            .map(|last_week| round(*sum as f64 / *last_week as f64, 2));
+
+        statistics.data.push(StatisticsData {
+            date: convert_date_to_jst(date),
+            sum: *sum,
+            weekly_sum: *weekly_sum,
+            moving_average: weekly_sum.map(|weekly_sum| round(weekly_sum as f64 / WINDOW as f64, 1)),
+            week_over_week,
+            per_100k: per_100k(*sum, population),
+            weekly_per_100k: weekly_sum.map(|weekly_sum| per_100k(weekly_sum, population))
+        });
+    }
+
+    statistics
+
+}
+
+fn per_100k(sum: i64, population: u64) -> f64 {
+
+    if population == 0 {
+        return 0.0;
+    }
+
+    round(sum as f64 / population as f64 * 100000.0, 2)
+
+}
+
+// 小数第digits位までに丸める
+fn round(value: f64, digits: i32) -> f64 {
+
+    let scale: f64 = 10f64.powi(digits);
+
+    (value * scale).round() / scale
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::date_format::jst;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn statistics(values: Vec<i64>, population: u64) -> Statistics {
+        let summary: Summary = TimeSeries { start: NaiveDate::from_ymd_opt(2021, 8, 1), values }
+            .to_summary(jst().with_ymd_and_hms(2021, 9, 1, 0, 0, 0).unwrap());
+
+        statistics_generate(&summary, population)
+    }
+
+    #[test]
+    fn computes_weekly_sum_and_moving_average() {
+        let statistics: Statistics = statistics(vec![1, 2, 3, 4, 5, 6, 7, 15], 100000);
+
+        assert_eq!(statistics.data[5].weekly_sum, None);
+        assert_eq!(statistics.data[5].moving_average, None);
+        assert_eq!(statistics.data[6].weekly_sum, Some(28));
+        assert_eq!(statistics.data[6].moving_average, Some(4.0));
+        assert_eq!(statistics.data[7].weekly_sum, Some(42));
+        assert_eq!(statistics.data[7].moving_average, Some(6.0));
+    }
+
+    #[test]
+    fn computes_week_over_week_only_when_last_week_is_positive() {
+        let statistics: Statistics = statistics(vec![4, 0, 1, 1, 1, 1, 1, 6, 5], 100000);
+
+        assert_eq!(statistics.data[6].week_over_week, None);
+        assert_eq!(statistics.data[7].week_over_week, Some(1.5));
+        // 前の週の同じ曜日が0の日は、比を求めない
+        assert_eq!(statistics.data[8].week_over_week, None);
+    }
+
+    #[test]
+    fn computes_per_100k_for_population() {
+        let statistics: Statistics = statistics(vec![26; 7], 2578087);
+
+        assert_eq!(statistics.population, 2578087);
+        assert_eq!(statistics.data[0].per_100k, 1.01);
+        assert_eq!(statistics.data[6].weekly_per_100k, Some(7.06));
+
+        assert_eq!(self::statistics(vec![5], 0).data[0].per_100k, 0.0);
+    }
+
+}
@@ -0,0 +1,143 @@
+use chrono::Duration;
+use crate::structs::{
+    summary::Summary,
+    time_series::TimeSeries,
+    trend::{Trend, TrendConfig, TrendData, Trends}
+};
+
+// 日ごとの陽性者数から、直近window日間とその前のwindow日間の合計を比べて傾向を判定する
+// 合計の比から1日あたりの増加率を求め、増加していれば倍加時間、減少していれば半減時間を求める
+pub fn trend_generate(patients_summary: &Summary, config: TrendConfig) -> Trends {
+
+    let sums: TimeSeries<Option<i64>> = TimeSeries::from_summary(patients_summary).window_sum(config.window);
+
+    let mut trends: Trends = Trends {
+        config,
+        data: Vec::new(),
+        last_update: patients_summary.last_update
+    };
+
+    for (date, sum) in sums.iter() {
+        let (sum, previous_sum): (i64, i64) = match (sum, sums.get(date - Duration::days(config.window as i64))) {
+            (Some(sum), Some(Some(previous_sum))) => (*sum, *previous_sum),
+            _ => continue
+        };
+
+        let ratio: Option<f64> = if previous_sum > 0 {
+            Some(sum as f64 / previous_sum as f64)
+        } else {
+            None
+        };
+
+        // 1日あたりの増加率（指数関数的な増減を仮定する）
+        let rate: Option<f64> = ratio
+            .filter(|ratio| *ratio > 0.0)
+            .map(|ratio| ratio.ln() / config.window as f64);
+
+        let trend: Trend = match ratio {
+            Some(ratio) if ratio >= config.increase => Trend::Increasing,
+            Some(ratio) if ratio <= config.decrease => Trend::Decreasing,
+            Some(_) => Trend::Flat,
+            // 前の期間に陽性者がいなければ、直近の期間に陽性者がいる場合を増加とする
+            None if sum > 0 => Trend::Increasing,
+            None => Trend::Flat
+        };
+
+        trends.data.push(TrendData {
+            date,
+            sum,
+            previous_sum,
+            ratio: ratio.map(round),
+            doubling_time: rate.filter(|rate| *rate > 0.0).map(|rate| round(2f64.ln() / rate)),
+            halving_time: rate.filter(|rate| *rate < 0.0).map(|rate| round(2f64.ln() / -rate)),
+            trend
+        });
+    }
+
+    trends
+
+}
+
+// 小数第2位までに丸める
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::date_format::jst;
+    use chrono::{NaiveDate, TimeZone};
+
+    const CONFIG: TrendConfig = TrendConfig { window: 7, increase: 1.1, decrease: 0.9 };
+
+    fn trends(values: Vec<i64>) -> Trends {
+        let summary: Summary = TimeSeries { start: NaiveDate::from_ymd_opt(2021, 8, 1), values }
+            .to_summary(jst().with_ymd_and_hms(2021, 9, 1, 0, 0, 0).unwrap());
+
+        trend_generate(&summary, CONFIG)
+    }
+
+    fn last(values: Vec<i64>) -> TrendData {
+        trends(values).data.last().cloned().unwrap()
+    }
+
+    #[test]
+    fn starts_after_two_windows() {
+        let trends: Trends = trends(vec![1; 14]);
+
+        assert_eq!(trends.data.len(), 1);
+        assert_eq!(trends.data[0].date, NaiveDate::from_ymd_opt(2021, 8, 14).unwrap());
+    }
+
+    #[test]
+    fn doubling_gives_window_as_doubling_time() {
+        let data: TrendData = last([vec![10; 7], vec![20; 7]].concat());
+
+        assert_eq!((data.sum, data.previous_sum), (140, 70));
+        assert_eq!(data.ratio, Some(2.0));
+        assert_eq!(data.doubling_time, Some(7.0));
+        assert_eq!(data.halving_time, None);
+        assert!(data.trend == Trend::Increasing);
+    }
+
+    #[test]
+    fn halving_gives_window_as_halving_time() {
+        let data: TrendData = last([vec![20; 7], vec![10; 7]].concat());
+
+        assert_eq!(data.ratio, Some(0.5));
+        assert_eq!(data.doubling_time, None);
+        assert_eq!(data.halving_time, Some(7.0));
+        assert!(data.trend == Trend::Decreasing);
+    }
+
+    #[test]
+    fn classifies_by_thresholds() {
+        // 比がちょうど閾値の場合は、増加または減少とする
+        assert!(last([vec![10; 7], vec![11; 7]].concat()).trend == Trend::Increasing);
+        assert!(last([vec![10; 7], vec![9; 7]].concat()).trend == Trend::Decreasing);
+        assert!(last([vec![10; 7], vec![10; 7]].concat()).trend == Trend::Flat);
+        assert_eq!(last([vec![10; 7], vec![10; 7]].concat()).doubling_time, None);
+    }
+
+    #[test]
+    fn handles_zero_sums() {
+        let data: TrendData = last([vec![0; 7], vec![1; 7]].concat());
+
+        assert_eq!(data.ratio, None);
+        assert!(data.trend == Trend::Increasing);
+
+        let data: TrendData = last(vec![0; 14]);
+
+        assert_eq!(data.ratio, None);
+        assert!(data.trend == Trend::Flat);
+
+        let data: TrendData = last([vec![1; 7], vec![0; 7]].concat());
+
+        assert_eq!(data.ratio, Some(0.0));
+        assert_eq!(data.halving_time, None);
+        assert!(data.trend == Trend::Decreasing);
+    }
+
+}
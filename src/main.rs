@@ -1,15 +1,16 @@
+mod errors;
 mod generates;
 mod structs;
 mod utils;
 
 use regex::Regex;
 use std::path::Path;
-use clap::Parser;
+use clap::{ErrorKind, Parser};
 use chrono::TimeZone;
 use mail_parser::MimeHeaders;
-use mail_parser::Message;
-use crate::utils::date_format::convert_datetime_to_date_and_time;
-use chrono::{DateTime, Local, Utc};
+use mail_parser::{Message, MessagePart};
+use crate::utils::date_format::{convert_datetime_to_date_and_time, jst};
+use chrono::{DateTime, FixedOffset, Utc};
 use oauth2::{
     AuthUrl,
     ClientId,
@@ -24,33 +25,66 @@ use oauth2::{
     TokenResponse,
     TokenUrl
 };
-use std::io::{Write};
 use std::fs::{File};
+use std::io::{Write};
+use std::process;
 
 extern crate imap;
 extern crate native_tls;
 
+use crate::errors::app_error::AppError;
 use crate::generates::{
+    active_cases_generate::active_cases_generate,
+    areas_summary_generate::areas_summary_generate,
+    breakdown_generate::breakdown_generate,
     inspections_summary_generate::inspections_summary_generate,
+    inspections_time_series_generate::inspections_time_series_generate,
     json::{
+        jsonize_active_cases_generate::jsonize_active_cases_generate,
+        jsonize_areas_summary_generate::jsonize_areas_summary_generate,
+        jsonize_breakdown_generate::jsonize_breakdown_generate,
+        jsonize_inspections_time_series_generate::jsonize_inspections_time_series_generate,
         jsonize_main_summary_generate::jsonize_main_summary_generate,
         jsonize_summary_generate::jsonize_summary_generate,
-        jsonize_patients_generate::jsonize_patients_generate
+        jsonize_patients_generate::jsonize_patients_generate,
+        jsonize_period_summary_generate::jsonize_period_summary_generate,
+        jsonize_positivity_generate::jsonize_positivity_generate,
+        jsonize_reproduction_number_generate::jsonize_reproduction_number_generate,
+        jsonize_statistics_generate::jsonize_statistics_generate,
+        jsonize_trend_generate::jsonize_trend_generate
     },
     main_summary_generate::main_summary_generate,
     news_generate::news_generate,
-    patients_summary_generate::patients_summary_generate
+    patients_summary_generate::patients_summary_generate,
+    period_summary_generate::period_summary_generate,
+    positivity_generate::positivity_generate,
+    reproduction_number_generate::reproduction_number_generate,
+    statistics_generate::statistics_generate,
+    trend_generate::trend_generate
 };
 use structs::{
+    active_cases::ActiveCases,
+    column_series::InspectionsTimeSeries,
+    correction_policy::CorrectionPolicy,
+    diagnostics::Diagnostics,
     last_update::LastUpdate,
+    layout::{Layout, SheetLayout, Worksheet, WorksheetGroup},
     main_summary::MainSummary,
     news::News,
     patient::Patient,
+    period_summary::Period,
+    positivity::Positivity,
+    reproduction_number::ReproductionNumberConfig,
     sumdata::SumData,
-    summary::Summary
+    summary::Summary,
+    trend::TrendConfig
 };
-use calamine::{Reader, Xlsx, open_workbook};
+use calamine::{DataType, Range, Reader, Xlsx, open_workbook};
 use generates::patients_generate::{patients_generate};
+use utils::detect_layout::{detect_layout, header_row, layout_worksheet_names};
+use utils::find_worksheets::find_worksheets;
+use utils::normalize_municipality::KYOTO;
+use utils::write_output::write_output;
 
 #[derive(Parser)]
 #[clap(author, version, about)]
@@ -72,7 +106,69 @@ struct Args {
     #[clap(long)]
     refresh_token: String,
     #[clap(long)]
-    query: String
+    query: String,
+    #[clap(long, default_value = "diagnostics.json")]
+    diagnostics: String,
+    #[clap(long, value_enum, default_value = "keep")]
+    correction_policy: CorrectionPolicy,
+    #[clap(long, default_value = "0")]
+    positivity_lag: i64,
+    // 令和2年国勢調査による京都府の人口（市町村の人口の表と同じ値を使う）
+    #[clap(long, default_value_t = KYOTO.population)]
+    population: u64,
+    // 実効再生産数の推定に使う発症間隔の平均と標準偏差（日）、事前分布、期間の日数
+    #[clap(long, default_value = "4.8", value_parser = parse_positive)]
+    serial_interval_mean: f64,
+    #[clap(long, default_value = "2.3", value_parser = parse_positive)]
+    serial_interval_sd: f64,
+    #[clap(long, default_value = "1", value_parser = parse_positive)]
+    rt_prior_shape: f64,
+    #[clap(long, default_value = "5", value_parser = parse_positive)]
+    rt_prior_scale: f64,
+    #[clap(long, default_value = "7", value_parser = clap::value_parser!(u64).range(1..))]
+    rt_window: u64,
+    // 傾向の判定に使う期間の日数と、増加、減少とみなす前の期間との比
+    #[clap(long, default_value = "7", value_parser = clap::value_parser!(u64).range(1..))]
+    trend_window: u64,
+    #[clap(long, default_value = "1.1", value_parser = parse_positive)]
+    trend_increase: f64,
+    #[clap(long, default_value = "0.9", value_parser = parse_positive)]
+    trend_decrease: f64
+}
+
+// 正の数のみを受け付ける
+fn parse_positive(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(number) if number > 0.0 && number.is_finite() => Ok(number),
+        _ => Err(format!("{} is not a positive number", value))
+    }
+}
+
+// 引数のパースの失敗を終了コードの決まったエラーに変換する
+// --helpと--versionは、その出力を表示した上でNoneを返す
+fn convert_clap_error(e: clap::Error) -> Option<AppError> {
+    match e.kind() {
+        ErrorKind::DisplayHelp | ErrorKind::DisplayVersion => {
+            let _ = e.print();
+            None
+        },
+        _ => Some(AppError::Config(e.to_string().trim().trim_start_matches("error: ").to_string()))
+    }
+}
+
+// 個々の値としては正しくても、組み合わせとして矛盾する引数をエラーにする
+fn validate_args(args: &Args) -> Result<(), AppError> {
+
+    // 増加と減少の範囲が重ならないよう、減少 < 1 < 増加 とする
+    if !(args.trend_decrease < 1.0 && 1.0 < args.trend_increase) {
+        return Err(AppError::Config(format!(
+            "--trend-decrease ({}) must be less than 1 and --trend-increase ({}) must be greater than 1",
+            args.trend_decrease, args.trend_increase
+        )));
+    }
+
+    Ok(())
+
 }
 
 struct OAuth2 {
@@ -91,58 +187,92 @@ impl imap::Authenticator for OAuth2 {
 }
 
 fn main() {
+    // コマンドライン引数をパース
+    // 誤った引数は、新しいメールが無い場合（終了コード2）と区別できるよう設定の誤りとして扱う
+    let args: Args = match Args::try_parse() {
+        Ok(args) => args,
+        Err(e) => match convert_clap_error(e) {
+            Some(e) => {
+                eprintln!("{}", e);
+                process::exit(e.exit_code());
+            },
+            None => process::exit(0)
+        }
+    };
+
+    match run(args) {
+        Ok(()) => println!("Done!"),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(e.exit_code());
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), AppError> {
+    // メールを取得する前に、引数の組み合わせを確かめる
+    validate_args(&args)?;
+
     // 一時ディレクトリを作成
     let tmp_dir = "tmp";
 
-    // コマンドライン引数をパース
-    let args = Args::parse();
+    let (filename, last_update) = fetch_workbook(&args, tmp_dir)?;
+    let result = generate_data(&args, &[tmp_dir, "/", &filename].concat(), last_update);
+
+    // 一時ディレクトリを削除
+    println!("Remove temporary directory...");
+    // 後始末の失敗で本来のエラーを上書きしないよう、生成結果を優先する
+    let cleanup: Result<(), AppError> = std::fs::remove_dir_all(tmp_dir)
+        .map_err(|e| AppError::Output(format!("{}: {}", tmp_dir, e)));
+
+    result.and(cleanup)
+}
+
+// メールサーバから最新のワークブックを取得し、一時ディレクトリに保存する
+fn fetch_workbook(args: &Args, tmp_dir: &str) -> Result<(String, DateTime<FixedOffset>), AppError> {
 
     println!("Fetching workbook from mail server...");
 
     let oauth2_client = BasicClient::new(
         ClientId::new(args.client_id.to_string()),
         Some(ClientSecret::new(args.client_secret.clone())),
-        AuthUrl::new(args.auth_url).unwrap(),
-        Some(TokenUrl::new(args.token_url.clone()).unwrap())
+        AuthUrl::new(args.auth_url.clone()).map_err(|e| AppError::Auth(e.to_string()))?,
+        Some(TokenUrl::new(args.token_url.clone()).map_err(|e| AppError::Auth(e.to_string()))?)
     );
     let token_result = oauth2_client
         .exchange_refresh_token(&RefreshToken::new(args.refresh_token.clone()))
         .request(http_client)
-        .expect("Failed to request an access token.");
+        .map_err(|e| AppError::Auth(format!("Failed to request an access token: {}", e)))?;
 
     // TLSコネクタを作成
-    let tls = native_tls::TlsConnector::builder().build().unwrap();
+    let tls = native_tls::TlsConnector::builder().build()
+        .map_err(|e| AppError::Imap(e.to_string()))?;
     // クライアントを作成
     let client = imap::connect((args.server.clone(), args.port), args.server.clone(), &tls)
-        .unwrap();
+        .map_err(|e| AppError::Imap(e.to_string()))?;
     // IMAPサーバへログイン
     let auth = OAuth2 {
-        user: args.account,
+        user: args.account.clone(),
         access_token: token_result.access_token().secret().clone()
     };
 
     let mut imap_session = match client.authenticate("XOAUTH2", &auth) {
         Ok(c) => c,
-        #[allow(unused_variables)]
-        Err((e, unauthorized_client)) => {
-            eprintln!("Authentication is failed: {}", e);
-            return;
-        }
+        Err((e, _)) => return Err(AppError::Auth(e.to_string()))
     };
 
     //メールボックスを選択する
-    imap_session.select("INBOX").unwrap();
+    imap_session.select("INBOX").map_err(|e| AppError::Imap(e.to_string()))?;
 
-    let mut found: bool = false;
-    let mut filename: String = String::from("");
-    let mut last_update: DateTime<Local> = Local::now();
+    let mut workbook: Option<(String, DateTime<FixedOffset>)> = None;
+    let now: DateTime<FixedOffset> = Utc::now().with_timezone(&jst());
     
     // メールボックスの内容を読み込む
-    println!("{}", &last_update.format("%d-%b-%Y "));
-    let result = imap_session.search(["SENTSINCE ", &last_update.format("\"%d-%b-%Y\" ").
+    println!("{}", &now.format("%d-%b-%Y "));
+    let result = imap_session.search(["SENTSINCE ", &now.format("\"%d-%b-%Y\" ").
         to_string(), &args.query]
         .concat())
-        .unwrap();
+        .map_err(|e| AppError::Imap(e.to_string()))?;
     let mut result_vec: Vec<u32> = Vec::new();
 
     for res in result {
@@ -151,151 +281,310 @@ fn main() {
     result_vec.sort();
     result_vec.reverse();
 
+    let regex = Regex::new("[0-9]{8}data.xlsx").unwrap();
+
     for res in result_vec {
         // メッセージを読み込む
         let messages = imap_session.fetch(res.to_string(), "RFC822")
-        .unwrap();
-        let regex = Regex::new("[0-9]{8}data.xlsx").unwrap();
+            .map_err(|e| AppError::Imap(e.to_string()))?;
 
         // メッセージの内容を読み込み、一時的に保存する
         for message in &messages {
-            let body = message.body().unwrap();
-            let parsed = Message::parse(body).unwrap();
-            let mail_date = parsed.get_date().unwrap();
-
-            filename = parsed.get_attachment(0)
-                .unwrap()
-                .unwrap_binary()
-                .get_attachment_name()
-                .unwrap()
-                .to_string();
+            let parsed = match message.body().and_then(Message::parse) {
+                Some(parsed) => parsed,
+                None => continue
+            };
+
+            // 添付ファイルがバイナリでないメッセージは対象外
+            let attachment = match parsed.get_attachment(0) {
+                Some(MessagePart::Binary(part)) | Some(MessagePart::InlineBinary(part)) => part,
+                _ => continue
+            };
+
+            let filename: String = match attachment.get_attachment_name() {
+                Some(filename) => filename.to_string(),
+                None => continue
+            };
 
             if regex.is_match(&filename) {
-                last_update = Local.ymd(mail_date.year as i32, mail_date.month, mail_date.day)
-                    .and_hms(mail_date.hour, mail_date.minute, mail_date.second);
+                let mail_date = parsed.get_date()
+                    .ok_or_else(|| AppError::Attachment(format!("{}: the mail has no date", filename)))?;
+                // メールの日時をその時差で解釈した上で、日本標準時に揃える
+                let mail_offset: i32 = (mail_date.tz_hour * 60 * 60 + mail_date.tz_minute * 60) as i32;
+                let last_update: DateTime<FixedOffset> = FixedOffset::east_opt(if mail_date.tz_before_gmt { -mail_offset } else { mail_offset })
+                    .and_then(|offset| offset.with_ymd_and_hms(mail_date.year as i32, mail_date.month, mail_date.day,
+                        mail_date.hour, mail_date.minute, mail_date.second).single())
+                    .ok_or_else(|| AppError::Attachment(format!("{}: the mail has an invalid date", filename)))?
+                    .with_timezone(&jst());
                 
                 if !Path::new(tmp_dir).is_dir() {
-                    std::fs::create_dir(tmp_dir).unwrap();
+                    std::fs::create_dir(tmp_dir)
+                        .map_err(|e| AppError::Attachment(format!("{}: {}", tmp_dir, e)))?;
                 }
 
-                let attach = parsed.get_attachment(0)
-                    .unwrap()
-                    .unwrap_binary()
-                    .get_body();
+                let attach = attachment.get_body();
         
-                let mut file = File::create([tmp_dir.clone(), "/", &filename].concat()).unwrap();
-                file.write_all(attach).unwrap();
+                let path: String = [tmp_dir, "/", &filename].concat();
+                let mut file = File::create(&path)
+                    .map_err(|e| AppError::Attachment(format!("{}: {}", path, e)))?;
+                file.write_all(attach)
+                    .map_err(|e| AppError::Attachment(format!("{}: {}", path, e)))?;
 
-                found = true;
+                workbook = Some((filename, last_update));
                 break; 
             }
         }
 
-        if found == true {
+        if workbook.is_some() {
             break;
         }
     }
 
-    imap_session.logout().unwrap();
+    imap_session.logout().map_err(|e| AppError::Imap(e.to_string()))?;
+
+    workbook.ok_or(AppError::NoNewMail)
+
+}
+
+// ワークブックを解析し、JSONファイルを出力する
+fn generate_data(args: &Args, path: &str, last_update: DateTime<FixedOffset>) -> Result<(), AppError> {
 
     // ワークブックを読み出す
     println!("Loading a workbook...");
-    let worksheets_name: [&str; 3] = ["陽性者の属性", "PCR検査件数", "最新の情報"];
-    let mut workbook: Xlsx<_> = open_workbook([tmp_dir.clone(), "/", &filename].concat())
-        .expect("Failed to open workbook.");
+    let mut workbook: Xlsx<_> = open_workbook(path)
+        .map_err(|e: calamine::XlsxError| AppError::Workbook(e.to_string()))?;
+
+    let mut diagnostics: Diagnostics = Diagnostics::new();
 
-    let mut patients: Vec<Patient>;
-    let mut patients_summary: Summary;
-    let mut inspections_summary: Summary;
-    let mut main_summary: MainSummary;
-    let mut news: News;
+    // 出力するファイルのパスと内容
+    let mut outputs: Vec<(&str, String)> = Vec::new();
+
+    // レイアウトに含まれるワークシートを全て読み込み、ワークブックのレイアウトを判定する
+    let mut worksheets: Vec<WorksheetGroup> = Vec::new();
+
+    for name in layout_worksheet_names() {
+        worksheets.push((name, load_worksheets(&mut workbook, name)?));
+    }
+
+    let layout: &Layout = detect_layout(&worksheets).map_err(AppError::UnknownLayout)?;
+    println!("Detected workbook layout {}.", layout.version);
 
     // 陽性者の属性ワークシートを読み込む
-    for worksheet in worksheets_name {
-        
-        if let Some(Ok(range)) = workbook.worksheet_range(worksheet) {
+    let (patients_layout, patients_worksheets) = find_layout_worksheets(layout, &worksheets, "陽性者の属性")?;
 
-            if worksheet == "陽性者の属性" {
+    println!("Last update date is {}.", last_update);
 
-                println!("Last update date is {}.", last_update);
-            
-                println!("Generating patients data...");
-                patients = patients_generate(range.clone());
-    
-                println!("Generating jsonized patients data...");
-                let jsonize_patients: String = jsonize_patients_generate(patients.clone(), last_update);
+    println!("Generating patients data...");
+    let patients: Vec<Patient> = patients_generate(&patients_worksheets, patients_layout, &mut diagnostics);
 
-                let mut patients_date: Vec<DateTime<Utc>> = Vec::new();
-    
-                println!("Generating summary...");
-                for i in 0..patients.len() {
-                    patients_date.push(patients[i].clone().release_date.unwrap());
-                }
-    
-                patients_summary = patients_summary_generate(
-                        patients_date.clone(),
-                        patients_date[0].clone(),
-                        patients_date[patients_date.len() -1].clone(),
-                        last_update.clone())
-                    .unwrap();                
-                let jsonize_patients_summary: String = jsonize_summary_generate(
-                        patients_summary.clone(),
-                        last_update
-                    ).clone();
-    
-                // シリアライズした陽性者の属性を書き込む
-                let mut file = File::create("data/patients.json").unwrap();
-                file.write_all(jsonize_patients.as_bytes()).expect("Failed to output json file.");
+    println!("Generating jsonized patients data...");
+    let jsonize_patients: String = jsonize_patients_generate(patients.clone(), last_update);
 
-                let mut file = File::create("data/patients_summary.json").unwrap();
-                file.write_all(jsonize_patients_summary.as_bytes()).expect("Failed to output json file.");
+    let mut patients_date: Vec<DateTime<FixedOffset>> = Vec::new();
+    let mut undated: i64 = 0;
 
-            }
+    println!("Generating summary...");
+    for patient in &patients {
+        match patient.release_date {
+            Some(release_date) => patients_date.push(release_date),
+            None => undated += 1
+        }
+    }
 
-            if worksheet == "PCR検査件数" {
-                
-                inspections_summary = inspections_summary_generate(range.clone(), last_update);
-                main_summary = main_summary_generate(range.clone(), last_update);
+    outputs.push(("data/patients.json", jsonize_patients));
+
+    let mut patients_summary: Summary = patients_summary_generate(&patients_date, last_update);
+    // 公表日が分からない陽性者は、日付不明として別に数える
+    patients_summary.undated = Some(undated);
+
+    let jsonize_patients_summary: String = jsonize_summary_generate(patients_summary.clone());
 
-                let jsonize_inspections_summary: String = jsonize_summary_generate(
-                        inspections_summary.clone(),
-                        last_update)
-                    .clone();
-                let jsonize_main_summary: String = jsonize_main_summary_generate(main_summary, last_update);
+    outputs.push(("data/patients_summary.json", jsonize_patients_summary));
+    outputs.push(("data/areas_summary.json", jsonize_areas_summary_generate(areas_summary_generate(&patients, &KYOTO, last_update))));
+    outputs.push(("data/patients_by_age.json", jsonize_breakdown_generate(breakdown_generate(&patients, |patient| patient.age, |age| age.label(), last_update), true)));
+    outputs.push(("data/patients_by_gender.json", jsonize_breakdown_generate(breakdown_generate(&patients, |patient| patient.gender, |gender| gender.label(), last_update), false)));
+    let reproduction_number_config: ReproductionNumberConfig = ReproductionNumberConfig {
+        serial_interval_mean: args.serial_interval_mean,
+        serial_interval_sd: args.serial_interval_sd,
+        prior_shape: args.rt_prior_shape,
+        prior_scale: args.rt_prior_scale,
+        window: args.rt_window as usize
+    };
+    outputs.push(("data/reproduction_number.json", jsonize_reproduction_number_generate(reproduction_number_generate(&patients_summary, reproduction_number_config))));
+    let trend_config: TrendConfig = TrendConfig {
+        window: args.trend_window as usize,
+        increase: args.trend_increase,
+        decrease: args.trend_decrease
+    };
+    outputs.push(("data/trend.json", jsonize_trend_generate(trend_generate(&patients_summary, trend_config))));
+    outputs.push(("data/patients_statistics.json", jsonize_statistics_generate(statistics_generate(&patients_summary, args.population))));
+
+    // PCR検査件数ワークシートを読み込む
+    // 日ごとの行を複数のシートにまたがって読む仕組みは無いため、続きのシートがある場合はエラーにする
+    let (inspections_layout, inspections_worksheets) = find_layout_worksheets(layout, &worksheets, "PCR検査件数")?;
+    let (worksheet, range) = match inspections_worksheets.as_slice() {
+        [inspections_worksheet] => inspections_worksheet,
+        _ => return Err(AppError::Workbook(format!(
+            "the worksheet \"PCR検査件数\" must be a single sheet, but found {}",
+            inspections_worksheets.iter().map(|(sheet_name, _)| sheet_name.as_str()).collect::<Vec<&str>>().join(", ")
+        )))
+    };
 
-                let mut file = File::create("data/inspections_summary.json").unwrap();
-                file.write_all(jsonize_inspections_summary.as_bytes()).expect("Failed to output json file.");
+    let inspections_summary: Summary = inspections_summary_generate(range.clone(), inspections_layout, last_update, args.correction_policy, worksheet, &mut diagnostics);
+    let inspections_labels: Vec<String> = layout_header(&worksheets, inspections_layout);
+    let main_summary: MainSummary = main_summary_generate(range.clone(), &inspections_labels, inspections_layout, last_update, worksheet, &mut diagnostics);
+    let inspections_time_series: InspectionsTimeSeries = inspections_time_series_generate(range, inspections_layout, last_update, worksheet, &mut diagnostics);
 
-                file = File::create("data/main_summary.json").unwrap();
-                file.write_all(jsonize_main_summary.as_bytes()).expect("Failed to output json file.");
+    // 陽性率は、陽性患者数の公表日から報告の遅れの分だけ前の日の検査実施人数と突き合わせて求める
+    let positivity: Positivity = positivity_generate(&inspections_summary, &patients_summary, args.positivity_lag);
 
-            }
+    outputs.push(("data/inspections_statistics.json", jsonize_statistics_generate(statistics_generate(&inspections_summary, args.population))));
+    let active_cases: ActiveCases = active_cases_generate(&inspections_time_series, &patients, range, inspections_layout, worksheet, &mut diagnostics);
+    outputs.push(("data/active_cases.json", jsonize_active_cases_generate(active_cases)));
+    outputs.push(("data/weekly_summary.json", jsonize_period_summary_generate(period_summary_generate(Period::Week, &patients_summary, &inspections_summary, &inspections_time_series))));
+    outputs.push(("data/monthly_summary.json", jsonize_period_summary_generate(period_summary_generate(Period::Month, &patients_summary, &inspections_summary, &inspections_time_series))));
 
-            if worksheet == "最新の情報" {
+    let jsonize_inspections_summary: String = jsonize_summary_generate(inspections_summary);
+    let jsonize_main_summary: String = jsonize_main_summary_generate(main_summary);
+    let jsonize_inspections_time_series: String = jsonize_inspections_time_series_generate(inspections_time_series);
 
-                news = news_generate(&range);
-                let jsonize_news: String = serde_json::to_string_pretty(&news).unwrap();
+    outputs.push(("data/inspections_summary.json", jsonize_inspections_summary));
+    outputs.push(("data/main_summary.json", jsonize_main_summary));
+    outputs.push(("data/positivity.json", jsonize_positivity_generate(positivity)));
+    outputs.push(("data/inspections_time_series.json", jsonize_inspections_time_series));
 
-                let mut file = File::create("data/news.json").unwrap();
-                file.write_all(jsonize_news.as_bytes()).expect("Failed to output json file.");
+    // 最新の情報ワークシートは、無い場合は出力しない
+    let news_worksheets = find_layout_worksheets(layout, &worksheets, "最新の情報")
+        .map(|(_, news_worksheets)| news_worksheets)
+        .unwrap_or_default();
 
-            }
-    
-        }
+    if let Some((worksheet, range)) = news_worksheets.first() {
+
+        let news: News = news_generate(range, worksheet, &mut diagnostics);
+        let jsonize_news: String = serde_json::to_string_pretty(&news).unwrap();
+
+        outputs.push(("data/news.json", jsonize_news));
+
+    }
+
+    // 解析中に見つかった問題を報告する
+    write_output(&args.diagnostics, &serde_json::to_string_pretty(&diagnostics).unwrap())?;
+
+    if !diagnostics.is_empty() {
+        eprint!("{}", diagnostics.report());
+    }
 
+    if diagnostics.error_count() > 0 {
+        return Err(AppError::Parse(diagnostics.error_count()));
+    }
+
+    // 問題が無かった場合のみ、シリアライズしたデータを書き込む
+    for (path, content) in outputs {
+        write_output(path, &content)?;
     }
 
     let update: LastUpdate = LastUpdate {
         last_update: convert_datetime_to_date_and_time(last_update)
     };
 
-    let mut file = File::create("data/last_update.json").unwrap();
-    file.write_all(serde_json::to_string_pretty(&update).unwrap().as_bytes())
-        .expect("Failed to output json file.");
+    write_output("data/last_update.json", &serde_json::to_string_pretty(&update).unwrap())?;
 
-    // 一時ディレクトリを削除
-    println!("Remove temporary directory...");
-    std::fs::remove_dir_all(tmp_dir).unwrap();
+    Ok(())
+}
+
+// 名前が一致するワークシート（続きのシートを含む）を全て読み込む
+fn load_worksheets<R: std::io::Read + std::io::Seek>(workbook: &mut Xlsx<R>, name: &str) -> Result<Vec<Worksheet>, AppError> {
+
+    let sheet_names: Vec<String> = find_worksheets(workbook.sheet_names(), name);
+    let mut worksheets: Vec<Worksheet> = Vec::new();
+
+    for sheet_name in sheet_names {
+        match workbook.worksheet_range(&sheet_name) {
+            Some(Ok(range)) => worksheets.push((sheet_name, range)),
+            Some(Err(e)) => return Err(AppError::Workbook(format!("{}: {}", sheet_name, e))),
+            None => return Err(AppError::Workbook(format!("{}: failed to read the worksheet", sheet_name)))
+        }
+    }
+
+    Ok(worksheets)
+
+}
+
+// ワークシートの見出し行（見出し行が無い場合はレイアウトの列）を返す
+// 続きのシートがある場合は、最初のシートの見出しを使う
+fn layout_header(worksheets: &[WorksheetGroup], sheet_layout: &SheetLayout) -> Vec<String> {
+
+    worksheets.iter()
+        .filter(|(worksheet, _)| *worksheet == sheet_layout.name)
+        .flat_map(|(_, sheets)| sheets)
+        .next()
+        .and_then(|(_, range)| header_row(range, sheet_layout.name))
+        .unwrap_or_else(|| sheet_layout.columns.iter().map(|column| column.to_string()).collect())
+
+}
+
+// 判定したレイアウトでのワークシートの列の並びと、見出し行を取り除いたワークシートを返す
+// 必須のワークシートが見つからない場合はエラーにする
+fn find_layout_worksheets(layout: &'static Layout, worksheets: &[WorksheetGroup], name: &str) -> Result<(&'static SheetLayout, Vec<Worksheet>), AppError> {
+
+    let sheet_layout: &SheetLayout = layout.sheet(name)
+        .ok_or_else(|| AppError::Workbook(format!("the worksheet \"{}\" is not in the layout {}", name, layout.version)))?;
+
+    let mut found: Vec<Worksheet> = Vec::new();
+
+    for (sheet_name, range) in worksheets.iter().filter(|(worksheet, _)| *worksheet == name).flat_map(|(_, sheets)| sheets) {
+        // 見出し行があれば、データの行だけを残す（セル番地は元のワークシートのまま）
+        let range: Range<DataType> = match (header_row(range, name), range.start(), range.end()) {
+            (Some(_), Some(start), Some(end)) => range.range((start.0 + 1, start.1), end),
+            _ => range.clone()
+        };
+
+        found.push((sheet_name.clone(), range));
+    }
+
+    if found.is_empty() && sheet_layout.required {
+        return Err(AppError::Workbook(format!("the required worksheet \"{}\" was not found", name)));
+    }
+
+    Ok((sheet_layout, found))
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const REQUIRED: [&str; 19] = [
+        "covid19-scraping-rust", "--server", "imap.example.com", "--port", "993", "--account", "account",
+        "--auth-url", "https://example.com/auth", "--token-url", "https://example.com/token", "--client-id", "id",
+        "--client-secret", "secret", "--refresh-token", "token", "--query", "query"
+    ];
+
+    fn parse(extra: &[&str]) -> Result<Args, Option<i32>> {
+        Args::try_parse_from(REQUIRED.iter().chain(extra))
+            .map_err(|e| convert_clap_error(e).map(|e| e.exit_code()))
+    }
+
+    #[test]
+    fn accepts_valid_arguments() {
+        assert!(parse(&["--trend-window", "14"]).is_ok());
+    }
+
+    #[test]
+    fn maps_usage_and_value_errors_to_config() {
+        let missing: Option<i32> = Args::try_parse_from(["covid19-scraping-rust"]).err().and_then(convert_clap_error).map(|e| e.exit_code());
+
+        assert_eq!(missing, Some(10));
+        assert_eq!(parse(&["--trend-window", "0"]).err(), Some(Some(10)));
+        assert_eq!(parse(&["--serial-interval-mean", "-1"]).err(), Some(Some(10)));
+        assert_eq!(parse(&["--unknown"]).err(), Some(Some(10)));
+    }
+
+    #[test]
+    fn help_and_version_are_not_errors() {
+        assert_eq!(parse(&["--help"]).err(), Some(None));
+        assert_eq!(parse(&["--version"]).err(), Some(None));
+    }
 
-    println!("Done!");
 }
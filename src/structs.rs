@@ -1,7 +1,24 @@
+pub mod active_cases;
+pub mod age_group;
+pub mod area_summary;
+pub mod breakdown;
+pub mod column_series;
+pub mod correction_policy;
+pub mod diagnostics;
+pub mod gender;
 pub mod json;
 pub mod last_update;
+pub mod layout;
+pub mod leave_status;
 pub mod main_summary;
+pub mod municipality;
 pub mod news;
+pub mod statistics;
 pub mod sumdata;
 pub mod summary;
+pub mod time_series;
+pub mod trend;
 pub mod patient;
+pub mod period_summary;
+pub mod positivity;
+pub mod reproduction_number;
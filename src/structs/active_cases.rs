@@ -0,0 +1,26 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+// ある日までの陽性者数、退院者数、死亡者数の累計と、その差である療養中の患者数
+#[derive(Clone)]
+pub struct ActiveCasesData {
+    pub date: NaiveDate,
+    pub positives: i64,
+    pub recovered: i64,
+    pub deaths: i64,
+    pub active: i64
+}
+
+// PCR検査件数ワークシートの累計と、陽性者の属性から数えた人数の照合結果
+#[derive(Clone)]
+pub struct Reconciliation {
+    pub attr: String,
+    pub aggregate: i64,
+    pub patients: i64
+}
+
+#[derive(Clone)]
+pub struct ActiveCases {
+    pub data: Vec<ActiveCasesData>,
+    pub reconciliations: Vec<Reconciliation>,
+    pub last_update: DateTime<FixedOffset>
+}
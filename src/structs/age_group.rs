@@ -0,0 +1,94 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+// 年齢欄の数字と、単位や「以上」「未満」などの接尾辞
+static AGE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new("^([0-9]+)(?:歳|才)?(?:代|台)?(以上|未満)?$").unwrap());
+
+// 陽性者の年代
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AgeGroup {
+    UnderTen,
+    Decade(u32),
+    NinetyAndOver,
+    HundredAndOver,
+    Undisclosed,
+    Unknown
+}
+
+impl AgeGroup {
+
+    // 年齢欄の表記揺れ（例: 20、20代、20歳代、10未満、10歳未満、90以上）を吸収して年代に変換する
+    // 解釈できない値の場合はNoneを返す
+    pub fn parse(age_str: &str) -> Option<AgeGroup> {
+
+        let age_str: String = age_str.chars().filter(|c| !c.is_whitespace()).collect();
+
+        match age_str.as_str() {
+            "" | "-" | "－" | "不明" | "調査中" => return Some(AgeGroup::Unknown),
+            "非公表" | "非公開" => return Some(AgeGroup::Undisclosed),
+            "乳児" | "幼児" | "乳幼児" | "未就学児" => return Some(AgeGroup::UnderTen),
+            _ => ()
+        }
+
+        let matches = AGE_RE.captures(&age_str)?;
+        let age: u32 = matches[1].parse().ok()?;
+
+        match matches.get(2).map(|suffix| suffix.as_str()) {
+            Some("未満") if age <= 10 => Some(AgeGroup::UnderTen),
+            Some("未満") => None,
+            Some(_) if age >= 100 => Some(AgeGroup::HundredAndOver),
+            Some(_) if age >= 90 => Some(AgeGroup::NinetyAndOver),
+            Some(_) => None,
+            None if age < 10 => Some(AgeGroup::UnderTen),
+            None if age >= 100 => Some(AgeGroup::HundredAndOver),
+            None => Some(AgeGroup::Decade(age / 10 * 10))
+        }
+
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            AgeGroup::UnderTen => String::from("10歳未満"),
+            AgeGroup::Decade(decade) => format!("{}代", decade),
+            AgeGroup::NinetyAndOver => String::from("90歳以上"),
+            AgeGroup::HundredAndOver => String::from("100歳以上"),
+            AgeGroup::Undisclosed => String::from("非公表"),
+            AgeGroup::Unknown => String::from("")
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn parse_absorbs_notation() {
+        assert_eq!(AgeGroup::parse("20"), Some(AgeGroup::Decade(20)));
+        assert_eq!(AgeGroup::parse("20代"), Some(AgeGroup::Decade(20)));
+        assert_eq!(AgeGroup::parse("20歳代"), Some(AgeGroup::Decade(20)));
+        assert_eq!(AgeGroup::parse("25"), Some(AgeGroup::Decade(20)));
+        assert_eq!(AgeGroup::parse("10未満"), Some(AgeGroup::UnderTen));
+        assert_eq!(AgeGroup::parse("10歳未満"), Some(AgeGroup::UnderTen));
+        assert_eq!(AgeGroup::parse("乳幼児"), Some(AgeGroup::UnderTen));
+        assert_eq!(AgeGroup::parse("90以上"), Some(AgeGroup::NinetyAndOver));
+        assert_eq!(AgeGroup::parse("100歳以上"), Some(AgeGroup::HundredAndOver));
+    }
+
+    #[test]
+    fn parse_handles_unknown_and_undisclosed() {
+        assert_eq!(AgeGroup::parse(""), Some(AgeGroup::Unknown));
+        assert_eq!(AgeGroup::parse("調査中"), Some(AgeGroup::Unknown));
+        assert_eq!(AgeGroup::parse("非公表"), Some(AgeGroup::Undisclosed));
+    }
+
+    #[test]
+    fn parse_rejects_uninterpretable_values() {
+        assert_eq!(AgeGroup::parse("20未満"), None);
+        assert_eq!(AgeGroup::parse("50以上"), None);
+        assert_eq!(AgeGroup::parse("高齢者"), None);
+    }
+
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use crate::structs::time_series::TimeSeries;
+
+// 市区町村、地域、保健所の管轄区域ごとの陽性者数
+#[derive(Clone)]
+pub struct AreaSummary {
+    pub name: String,
+    // 市区町村の場合のみ
+    pub code: Option<String>,
+    pub population: u64,
+    // 公表日の分からない陽性者を含む累計
+    pub total: i64,
+    pub daily: TimeSeries<i64>,
+    // 週の初日と、その週の陽性者数の組
+    pub weekly: Vec<(NaiveDate, i64)>
+}
+
+#[derive(Clone)]
+pub struct AreasSummary {
+    pub municipalities: Vec<AreaSummary>,
+    pub regions: Vec<AreaSummary>,
+    pub health_centers: Vec<AreaSummary>,
+    // 都道府県外の陽性者数と、居住地の分からない陽性者数
+    pub outside: i64,
+    pub unknown: i64,
+    pub last_update: DateTime<FixedOffset>
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+// 1つの区分（年代や性別）の陽性者数
+#[derive(Clone)]
+pub struct BreakdownData {
+    pub label: String,
+    // 公表日の分からない陽性者を含む累計
+    pub total: i64,
+    // 最終更新日までの7日間
+    pub last_seven_days: i64,
+    // weeksの週ごとの陽性者数
+    pub weekly: Vec<i64>
+}
+
+// 陽性者の区分ごとの内訳
+#[derive(Clone)]
+pub struct Breakdown {
+    // 月曜日から始まる週（ISO週）の初日
+    pub weeks: Vec<NaiveDate>,
+    pub data: Vec<BreakdownData>,
+    pub last_update: DateTime<FixedOffset>
+}
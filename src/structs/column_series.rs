@@ -0,0 +1,23 @@
+use chrono::{DateTime, FixedOffset};
+
+// ある日の列の値と、前日からの増減
+#[derive(Clone)]
+pub struct ColumnData {
+    pub date: DateTime<FixedOffset>,
+    pub value: i64,
+    // 最初の日と前日の行が無い日は、前日の値が無いため増減も無い
+    pub delta: Option<i64>
+}
+
+// PCR検査件数ワークシートの1列分の推移
+#[derive(Clone)]
+pub struct ColumnSeries {
+    pub attr: String,
+    pub data: Vec<ColumnData>
+}
+
+#[derive(Clone)]
+pub struct InspectionsTimeSeries {
+    pub columns: Vec<ColumnSeries>,
+    pub last_update: DateTime<FixedOffset>
+}
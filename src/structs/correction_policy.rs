@@ -0,0 +1,12 @@
+use clap::ValueEnum;
+
+// 累計の値が前日より減った（訂正された）日の扱い
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CorrectionPolicy {
+    // 負の値のまま出力する
+    Keep,
+    // 0に切り上げて出力する
+    Clamp,
+    // 前の日から差し引き、報告の無かった日にはまとめて計上された値を按分する
+    Redistribute
+}
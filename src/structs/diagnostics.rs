@@ -0,0 +1,131 @@
+use calamine::{DataType, Range};
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+use crate::utils::cell_address::convert_position_to_a1;
+
+// 問題の重大度
+// Errorが1件でもあればデータを出力せず、Warningは報告のみ行う
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning
+}
+
+impl Severity {
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning"
+        }
+    }
+
+}
+
+// ワークブックの解析中に見つかった問題1件分
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub sheet: String,
+    pub cell: String,
+    pub raw: String,
+    pub expected: String
+}
+
+// 解析中に見つかった問題を全て集めておくための構造体
+#[derive(Default)]
+pub struct Diagnostics {
+    pub items: Vec<Diagnostic>
+}
+
+impl Diagnostics {
+
+    pub fn new() -> Diagnostics {
+        Diagnostics {
+            items: Vec::new()
+        }
+    }
+
+    // 範囲内の相対位置からセル番地と生の値を求めて問題を記録する
+    pub fn push_cell(&mut self, sheet: &str, range: &Range<DataType>, relative_position: (usize, usize), expected: &str) {
+        self.push(Severity::Error, sheet, range, relative_position, expected);
+    }
+
+    // データの出力は止めない、注意を促すための問題を記録する
+    pub fn warn_cell(&mut self, sheet: &str, range: &Range<DataType>, relative_position: (usize, usize), expected: &str) {
+        self.push(Severity::Warning, sheet, range, relative_position, expected);
+    }
+
+    fn push(&mut self, severity: Severity, sheet: &str, range: &Range<DataType>, relative_position: (usize, usize), expected: &str) {
+
+        let start: (u32, u32) = range.start().unwrap_or((0, 0));
+        let cell: String = convert_position_to_a1((
+            start.0 + relative_position.0 as u32,
+            start.1 + relative_position.1 as u32
+        ));
+        let raw: String = match range.get(relative_position) {
+            Some(value) => value.to_string(),
+            None => String::from("")
+        };
+
+        self.items.push(Diagnostic {
+            severity,
+            sheet: sheet.to_string(),
+            cell,
+            raw,
+            expected: expected.to_string()
+        });
+
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.items.iter().filter(|item| item.severity == Severity::Error).count()
+    }
+
+    // 人が読むための形式で問題を一覧にする
+    pub fn report(&self) -> String {
+
+        let mut report: String = format!("{} problem(s) found while parsing the workbook:\n", self.items.len());
+
+        for item in &self.items {
+            report.push_str(&format!(
+                "  {}: {}!{}: expected {}, but found {:?}\n",
+                item.severity.label(), item.sheet, item.cell, item.expected, item.raw
+            ));
+        }
+
+        report
+
+    }
+
+}
+
+impl Serialize for Diagnostic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Diagnostic", 5)?;
+        state.serialize_field("severity", self.severity.label())?;
+        state.serialize_field("sheet", &self.sheet)?;
+        state.serialize_field("cell", &self.cell)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("expected", &self.expected)?;
+        state.end()
+    }
+}
+
+impl Serialize for Diagnostics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Diagnostics", 3)?;
+        state.serialize_field("count", &self.items.len())?;
+        state.serialize_field("errors", &self.error_count())?;
+        state.serialize_field("diagnostics", &self.items)?;
+        state.end()
+    }
+}
@@ -0,0 +1,60 @@
+// 陽性者の性別
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Gender {
+    Male,
+    Female,
+    Undisclosed,
+    Unknown
+}
+
+impl Gender {
+
+    // 性別欄の表記揺れを吸収して変換する
+    // 解釈できない値の場合はNoneを返す
+    pub fn parse(gender_str: &str) -> Option<Gender> {
+
+        let gender_str: String = gender_str.chars().filter(|c| !c.is_whitespace()).collect();
+
+        match gender_str.as_str() {
+            "男性" | "男" | "M" | "m" | "male" | "Male" => Some(Gender::Male),
+            "女性" | "女" | "F" | "f" | "female" | "Female" => Some(Gender::Female),
+            "非公表" | "非公開" => Some(Gender::Undisclosed),
+            "" | "-" | "－" | "不明" | "調査中" => Some(Gender::Unknown),
+            _ => None
+        }
+
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Gender::Male => String::from("男性"),
+            Gender::Female => String::from("女性"),
+            Gender::Undisclosed => String::from("非公表"),
+            Gender::Unknown => String::from("")
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn parse_absorbs_notation() {
+        assert_eq!(Gender::parse("男性"), Some(Gender::Male));
+        assert_eq!(Gender::parse(" 男 "), Some(Gender::Male));
+        assert_eq!(Gender::parse("F"), Some(Gender::Female));
+        assert_eq!(Gender::parse("非公表"), Some(Gender::Undisclosed));
+        assert_eq!(Gender::parse(""), Some(Gender::Unknown));
+        assert_eq!(Gender::parse("調査中"), Some(Gender::Unknown));
+    }
+
+    #[test]
+    fn parse_rejects_uninterpretable_values() {
+        assert_eq!(Gender::parse("その他"), None);
+        assert_eq!(Gender::parse("X"), None);
+    }
+
+}
@@ -1,5 +1,14 @@
+pub mod jsonize_active_cases;
+pub mod jsonize_area_summary;
+pub mod jsonize_breakdown;
+pub mod jsonize_column_series;
+pub mod jsonize_statistics;
 pub mod jsonize_sumdata;
+pub mod jsonize_trend;
 pub mod jsonize_summary;
 pub mod jsonize_patient;
+pub mod jsonize_period_summary;
+pub mod jsonize_positivity;
+pub mod jsonize_reproduction_number;
 pub mod jsonize_main_summary;
 pub mod patients;
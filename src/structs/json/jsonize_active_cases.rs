@@ -0,0 +1,63 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizeActiveCasesData {
+    pub date: String,
+    pub positives: i64,
+    pub recovered: i64,
+    pub deaths: i64,
+    pub active: i64
+}
+
+pub struct JsonizeReconciliation {
+    pub attr: String,
+    pub aggregate: i64,
+    pub patients: i64
+}
+
+pub struct JsonizeActiveCases {
+    pub data: Vec<JsonizeActiveCasesData>,
+    pub reconciliations: Vec<JsonizeReconciliation>,
+    pub last_update: String
+}
+
+impl Serialize for JsonizeActiveCasesData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeActiveCasesData", 5)?;
+        state.serialize_field("日付", &self.date)?;
+        state.serialize_field("陽性患者数", &self.positives)?;
+        state.serialize_field("退院", &self.recovered)?;
+        state.serialize_field("死亡", &self.deaths)?;
+        state.serialize_field("療養中", &self.active)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeReconciliation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeReconciliation", 4)?;
+        state.serialize_field("attr", &self.attr)?;
+        state.serialize_field("PCR検査件数", &self.aggregate)?;
+        state.serialize_field("陽性者の属性", &self.patients)?;
+        state.serialize_field("差", &(self.aggregate - self.patients))?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeActiveCases {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeActiveCases", 3)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("reconciliations", &self.reconciliations)?;
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
@@ -0,0 +1,93 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizeAreaDailyData {
+    pub date: String,
+    pub sum: i64,
+    pub cumulative: i64
+}
+
+pub struct JsonizeAreaWeeklyData {
+    pub label: String,
+    pub start: String,
+    pub sum: i64
+}
+
+pub struct JsonizeAreaSummary {
+    pub name: String,
+    pub code: Option<String>,
+    pub population: u64,
+    pub total: i64,
+    pub total_per_100k: Option<f64>,
+    pub daily: Vec<JsonizeAreaDailyData>,
+    pub weekly: Vec<JsonizeAreaWeeklyData>
+}
+
+pub struct JsonizeAreasSummary {
+    pub municipalities: Vec<JsonizeAreaSummary>,
+    pub regions: Vec<JsonizeAreaSummary>,
+    pub health_centers: Vec<JsonizeAreaSummary>,
+    pub outside: i64,
+    pub unknown: i64,
+    pub last_update: String
+}
+
+impl Serialize for JsonizeAreaDailyData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeAreaDailyData", 3)?;
+        state.serialize_field("日付", &self.date)?;
+        state.serialize_field("小計", &self.sum)?;
+        state.serialize_field("累計", &self.cumulative)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeAreaWeeklyData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeAreaWeeklyData", 3)?;
+        state.serialize_field("期間", &self.label)?;
+        state.serialize_field("開始日", &self.start)?;
+        state.serialize_field("小計", &self.sum)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeAreaSummary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeAreaSummary", 6 + usize::from(self.code.is_some()))?;
+        state.serialize_field("名前", &self.name)?;
+        if let Some(code) = &self.code {
+            state.serialize_field("全国地方公共団体コード", code)?;
+        }
+        state.serialize_field("人口", &self.population)?;
+        state.serialize_field("累計", &self.total)?;
+        state.serialize_field("累計（人口10万人あたり）", &self.total_per_100k)?;
+        state.serialize_field("data", &self.daily)?;
+        state.serialize_field("weekly", &self.weekly)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeAreasSummary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeAreasSummary", 6)?;
+        state.serialize_field("municipalities", &self.municipalities)?;
+        state.serialize_field("regions", &self.regions)?;
+        state.serialize_field("health_centers", &self.health_centers)?;
+        state.serialize_field("府外", &self.outside)?;
+        state.serialize_field("不明", &self.unknown)?;
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
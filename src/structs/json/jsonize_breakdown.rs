@@ -0,0 +1,82 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizeWeek {
+    pub label: String,
+    pub start: String
+}
+
+pub struct JsonizeBreakdownData {
+    pub label: String,
+    pub total: i64,
+    pub last_seven_days: i64,
+    pub weekly: Vec<i64>
+}
+
+// ヒートマップに使うための、区分×週の表
+pub struct JsonizeMatrix {
+    pub x: Vec<String>,
+    pub y: Vec<String>,
+    pub z: Vec<Vec<i64>>
+}
+
+pub struct JsonizeBreakdown {
+    pub weeks: Vec<JsonizeWeek>,
+    pub data: Vec<JsonizeBreakdownData>,
+    pub matrix: Option<JsonizeMatrix>,
+    pub last_update: String
+}
+
+impl Serialize for JsonizeWeek {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeWeek", 2)?;
+        state.serialize_field("期間", &self.label)?;
+        state.serialize_field("開始日", &self.start)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeBreakdownData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeBreakdownData", 4)?;
+        state.serialize_field("attr", &self.label)?;
+        state.serialize_field("累計", &self.total)?;
+        state.serialize_field("直近7日間", &self.last_seven_days)?;
+        state.serialize_field("週ごと", &self.weekly)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeMatrix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeMatrix", 3)?;
+        state.serialize_field("x", &self.x)?;
+        state.serialize_field("y", &self.y)?;
+        state.serialize_field("z", &self.z)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeBreakdown {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeBreakdown", 3 + usize::from(self.matrix.is_some()))?;
+        state.serialize_field("weeks", &self.weeks)?;
+        state.serialize_field("data", &self.data)?;
+        if let Some(matrix) = &self.matrix {
+            state.serialize_field("matrix", matrix)?;
+        }
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
@@ -0,0 +1,54 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizeColumnData {
+    pub date: String,
+    pub value: i64,
+    pub delta: Option<i64>
+}
+
+pub struct JsonizeColumnSeries {
+    pub attr: String,
+    pub data: Vec<JsonizeColumnData>
+}
+
+pub struct JsonizeInspectionsTimeSeries {
+    pub columns: Vec<JsonizeColumnSeries>,
+    pub last_update: String
+}
+
+impl Serialize for JsonizeColumnData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeColumnData", 3)?;
+        state.serialize_field("日付", &self.date)?;
+        state.serialize_field("値", &self.value)?;
+        state.serialize_field("前日比", &self.delta)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeColumnSeries {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeColumnSeries", 2)?;
+        state.serialize_field("attr", &self.attr)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeInspectionsTimeSeries {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeInspectionsTimeSeries", 2)?;
+        state.serialize_field("columns", &self.columns)?;
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
@@ -13,7 +13,7 @@ impl Serialize for JsonizeMainSummary {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("JsonizeMainSummary", 6)?;
+        let mut state = serializer.serialize_struct("JsonizeMainSummary", 4)?;
         state.serialize_field("attr", &self.attr)?;
         state.serialize_field("value", &self.value)?;
         state.serialize_field("children", &self.children)?;
@@ -5,10 +5,17 @@ use serde::ser::{Serialize, Serializer, SerializeStruct};
 pub struct JsonizePatient {
     pub number: i32,
     pub release_date: Option<String>,
+    pub release_date_japanese_era: Option<String>,
     pub place: String,
+    pub municipality: String,
+    pub municipality_code: Option<String>,
+    pub region: Option<String>,
     pub age_and_gender: String,
+    pub age: String,
+    pub gender: String,
     pub leave: Option<String>,
-    pub date: String,
+    pub leave_status: String,
+    pub date: Option<String>,
 }
 
 impl Serialize for JsonizePatient {
@@ -16,12 +23,19 @@ impl Serialize for JsonizePatient {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("JsonizePatient", 6)?;
+        let mut state = serializer.serialize_struct("JsonizePatient", 13)?;
         state.serialize_field("No", &self.number)?;
         state.serialize_field("リリース日", &self.release_date)?;
+        state.serialize_field("公表日", &self.release_date_japanese_era)?;
         state.serialize_field("居住地", &self.place)?;
+        state.serialize_field("居住地（正規化）", &self.municipality)?;
+        state.serialize_field("全国地方公共団体コード", &self.municipality_code)?;
+        state.serialize_field("地域", &self.region)?;
         state.serialize_field("年代と性別", &self.age_and_gender)?;
+        state.serialize_field("年代", &self.age)?;
+        state.serialize_field("性別", &self.gender)?;
         state.serialize_field("退院", &self.leave)?;
+        state.serialize_field("療養状況", &self.leave_status)?;
         state.serialize_field("date", &self.date)?;
         state.end()
     }
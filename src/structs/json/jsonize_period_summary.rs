@@ -0,0 +1,47 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizePeriodData {
+    pub label: String,
+    pub start: String,
+    pub end: String,
+    pub positives: i64,
+    pub inspections: i64,
+    pub deaths: i64,
+    pub recoveries: i64,
+    pub in_progress: bool
+}
+
+pub struct JsonizePeriodSummary {
+    pub data: Vec<JsonizePeriodData>,
+    pub last_update: String
+}
+
+impl Serialize for JsonizePeriodData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizePeriodData", 8)?;
+        state.serialize_field("期間", &self.label)?;
+        state.serialize_field("開始日", &self.start)?;
+        state.serialize_field("終了日", &self.end)?;
+        state.serialize_field("陽性患者数", &self.positives)?;
+        state.serialize_field("検査実施人数", &self.inspections)?;
+        state.serialize_field("死亡", &self.deaths)?;
+        state.serialize_field("退院", &self.recoveries)?;
+        state.serialize_field("集計中", &self.in_progress)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizePeriodSummary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizePeriodSummary", 2)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
@@ -0,0 +1,47 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizePositivityData {
+    pub date: String,
+    pub inspections: i64,
+    pub positives: i64,
+    pub rate: Option<f64>,
+    pub weekly_inspections: Option<i64>,
+    pub weekly_positives: Option<i64>,
+    pub weekly_rate: Option<f64>
+}
+
+pub struct JsonizePositivity {
+    pub data: Vec<JsonizePositivityData>,
+    pub lag: i64,
+    pub last_update: String
+}
+
+impl Serialize for JsonizePositivityData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizePositivityData", 7)?;
+        state.serialize_field("日付", &self.date)?;
+        state.serialize_field("検査実施人数", &self.inspections)?;
+        state.serialize_field("陽性患者数", &self.positives)?;
+        state.serialize_field("陽性率", &self.rate)?;
+        state.serialize_field("7日間検査実施人数", &self.weekly_inspections)?;
+        state.serialize_field("7日間陽性患者数", &self.weekly_positives)?;
+        state.serialize_field("7日間陽性率", &self.weekly_rate)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizePositivity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizePositivity", 3)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("lag", &self.lag)?;
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
@@ -0,0 +1,45 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizeReproductionNumberData {
+    pub date: String,
+    pub mean: f64,
+    pub lower: f64,
+    pub upper: f64
+}
+
+pub struct JsonizeReproductionNumber {
+    pub serial_interval_mean: f64,
+    pub serial_interval_sd: f64,
+    pub window: usize,
+    pub data: Vec<JsonizeReproductionNumberData>,
+    pub last_update: String
+}
+
+impl Serialize for JsonizeReproductionNumberData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeReproductionNumberData", 4)?;
+        state.serialize_field("日付", &self.date)?;
+        state.serialize_field("実効再生産数", &self.mean)?;
+        state.serialize_field("下限", &self.lower)?;
+        state.serialize_field("上限", &self.upper)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeReproductionNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeReproductionNumber", 5)?;
+        state.serialize_field("serial_interval_mean", &self.serial_interval_mean)?;
+        state.serialize_field("serial_interval_sd", &self.serial_interval_sd)?;
+        state.serialize_field("window", &self.window)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
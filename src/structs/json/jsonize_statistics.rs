@@ -0,0 +1,47 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizeStatisticsData {
+    pub date: String,
+    pub sum: i64,
+    pub weekly_sum: Option<i64>,
+    pub moving_average: Option<f64>,
+    pub week_over_week: Option<f64>,
+    pub per_100k: f64,
+    pub weekly_per_100k: Option<f64>
+}
+
+pub struct JsonizeStatistics {
+    pub data: Vec<JsonizeStatisticsData>,
+    pub population: u64,
+    pub last_update: String
+}
+
+impl Serialize for JsonizeStatisticsData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeStatisticsData", 7)?;
+        state.serialize_field("日付", &self.date)?;
+        state.serialize_field("小計", &self.sum)?;
+        state.serialize_field("7日間合計", &self.weekly_sum)?;
+        state.serialize_field("7日間移動平均", &self.moving_average)?;
+        state.serialize_field("前週比", &self.week_over_week)?;
+        state.serialize_field("人口10万人あたり", &self.per_100k)?;
+        state.serialize_field("7日間合計（人口10万人あたり）", &self.weekly_per_100k)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeStatistics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeStatistics", 3)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("population", &self.population)?;
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
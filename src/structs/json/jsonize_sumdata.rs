@@ -4,7 +4,8 @@ use serde::ser::{Serialize, Serializer, SerializeStruct};
 #[allow(non_snake_case)]
 pub struct JsonizeSumData {
     pub date: String,
-    pub sum: i64
+    pub sum: i64,
+    pub note: Option<String>
 }
 
 impl Serialize for JsonizeSumData {
@@ -12,9 +13,12 @@ impl Serialize for JsonizeSumData {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("JsonizeSumData", 6)?;
+        let mut state = serializer.serialize_struct("JsonizeSumData", 2 + usize::from(self.note.is_some()))?;
         state.serialize_field("日付", &self.date)?;
         state.serialize_field("小計", &self.sum)?;
+        if let Some(note) = &self.note {
+            state.serialize_field("備考", note)?;
+        }
         state.end()
     }
 }
@@ -5,6 +5,7 @@ use serde::ser::{Serialize, Serializer, SerializeStruct};
 #[allow(non_snake_case)]
 pub struct JsonizeSummary {
     pub data: Vec<JsonizeSumData>,
+    pub undated: Option<i64>,
     pub last_update: String
 }
 
@@ -13,8 +14,11 @@ impl Serialize for JsonizeSummary {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("JsonizeSummary", 6)?;
+        let mut state = serializer.serialize_struct("JsonizeSummary", 2 + usize::from(self.undated.is_some()))?;
         state.serialize_field("data", &self.data)?;
+        if let Some(undated) = &self.undated {
+            state.serialize_field("日付不明", undated)?;
+        }
         state.serialize_field("last_update", &self.last_update)?;
         state.end()
     }
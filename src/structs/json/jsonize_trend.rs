@@ -0,0 +1,51 @@
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+pub struct JsonizeTrendData {
+    pub date: String,
+    pub sum: i64,
+    pub previous_sum: i64,
+    pub ratio: Option<f64>,
+    pub doubling_time: Option<f64>,
+    pub halving_time: Option<f64>,
+    pub trend: String
+}
+
+pub struct JsonizeTrends {
+    pub window: usize,
+    pub increase: f64,
+    pub decrease: f64,
+    pub data: Vec<JsonizeTrendData>,
+    pub last_update: String
+}
+
+impl Serialize for JsonizeTrendData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeTrendData", 7)?;
+        state.serialize_field("日付", &self.date)?;
+        state.serialize_field("期間合計", &self.sum)?;
+        state.serialize_field("前期間合計", &self.previous_sum)?;
+        state.serialize_field("比", &self.ratio)?;
+        state.serialize_field("倍加時間", &self.doubling_time)?;
+        state.serialize_field("半減時間", &self.halving_time)?;
+        state.serialize_field("傾向", &self.trend)?;
+        state.end()
+    }
+}
+
+impl Serialize for JsonizeTrends {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("JsonizeTrends", 5)?;
+        state.serialize_field("window", &self.window)?;
+        state.serialize_field("increase", &self.increase)?;
+        state.serialize_field("decrease", &self.decrease)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("last_update", &self.last_update)?;
+        state.end()
+    }
+}
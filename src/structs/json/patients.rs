@@ -11,7 +11,7 @@ impl Serialize for Patients {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Patients", 6)?;
+        let mut state = serializer.serialize_struct("Patients", 2)?;
         state.serialize_field("data", &self.data)?;
         state.serialize_field("last_update", &self.last_update)?;
         state.end()
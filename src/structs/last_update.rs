@@ -9,7 +9,7 @@ impl Serialize for LastUpdate {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("LastUpdate", 6)?;
+        let mut state = serializer.serialize_struct("LastUpdate", 1)?;
         state.serialize_field("last_update", &self.last_update)?;
         state.end()
     }
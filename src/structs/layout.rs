@@ -0,0 +1,40 @@
+use calamine::{DataType, Range};
+use crate::utils::normalize_text::normalize_text;
+
+// ワークシートの名前と、その内容
+pub type Worksheet = (String, Range<DataType>);
+
+// レイアウト上のワークシートの名前と、その名前に一致したワークシート（続きのシートを含む）
+pub type WorksheetGroup = (&'static str, Vec<Worksheet>);
+
+// ワークシートごとの列の並び
+// 見出し行の無いワークシートでも、列の意味を表すために見出しを持っておく
+pub struct SheetLayout {
+    pub name: &'static str,
+    pub required: bool,
+    pub columns: &'static [&'static str]
+}
+
+// ワークブックのレイアウト（ワークシートの組み合わせと、それぞれの列の並び）
+pub struct Layout {
+    pub version: &'static str,
+    pub sheets: &'static [SheetLayout]
+}
+
+impl SheetLayout {
+
+    // 見出しに一致する列の位置を返す
+    pub fn column(&self, label: &str) -> Option<usize> {
+        let label: String = normalize_text(label);
+        self.columns.iter().position(|column| normalize_text(column) == label)
+    }
+
+}
+
+impl Layout {
+
+    pub fn sheet(&self, name: &str) -> Option<&'static SheetLayout> {
+        self.sheets.iter().find(|sheet| sheet.name == name)
+    }
+
+}
@@ -0,0 +1,95 @@
+// 陽性者の退院・療養の状況
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LeaveStatus {
+    Discharged,
+    Died,
+    Hospitalized,
+    Accommodation,
+    HomeCare,
+    Adjusting,
+    Unknown
+}
+
+// 退院欄に書かれる表記と状況の対応表
+const LEAVE_STATUS_TABLE: &[(&str, LeaveStatus)] = &[
+    ("〇", LeaveStatus::Discharged),
+    ("○", LeaveStatus::Discharged),
+    ("◯", LeaveStatus::Discharged),
+    ("退院", LeaveStatus::Discharged),
+    ("退院済", LeaveStatus::Discharged),
+    ("退院済み", LeaveStatus::Discharged),
+    ("済", LeaveStatus::Discharged),
+    ("療養解除", LeaveStatus::Discharged),
+    ("療養終了", LeaveStatus::Discharged),
+    ("死亡", LeaveStatus::Died),
+    ("死去", LeaveStatus::Died),
+    ("入院", LeaveStatus::Hospitalized),
+    ("入院中", LeaveStatus::Hospitalized),
+    ("宿泊", LeaveStatus::Accommodation),
+    ("宿泊療養", LeaveStatus::Accommodation),
+    ("宿泊施設", LeaveStatus::Accommodation),
+    ("宿泊療養中", LeaveStatus::Accommodation),
+    ("自宅", LeaveStatus::HomeCare),
+    ("自宅療養", LeaveStatus::HomeCare),
+    ("自宅療養中", LeaveStatus::HomeCare),
+    ("調整中", LeaveStatus::Adjusting),
+    ("入院調整中", LeaveStatus::Adjusting),
+    ("療養調整中", LeaveStatus::Adjusting),
+    ("", LeaveStatus::Unknown),
+    ("-", LeaveStatus::Unknown),
+    ("－", LeaveStatus::Unknown),
+    ("不明", LeaveStatus::Unknown)
+];
+
+impl LeaveStatus {
+
+    // 退院欄の表記を対応表に従って状況に変換する
+    // 対応表に無い値の場合はNoneを返す
+    pub fn parse(leave_str: &str) -> Option<LeaveStatus> {
+
+        let leave_str: String = leave_str.chars().filter(|c| !c.is_whitespace()).collect();
+
+        LEAVE_STATUS_TABLE.iter()
+            .find(|(text, _)| *text == leave_str)
+            .map(|(_, status)| *status)
+
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            LeaveStatus::Discharged => String::from("退院"),
+            LeaveStatus::Died => String::from("死亡"),
+            LeaveStatus::Hospitalized => String::from("入院中"),
+            LeaveStatus::Accommodation => String::from("宿泊療養"),
+            LeaveStatus::HomeCare => String::from("自宅療養"),
+            LeaveStatus::Adjusting => String::from("調整中"),
+            LeaveStatus::Unknown => String::from("")
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn parse_follows_table() {
+        assert_eq!(LeaveStatus::parse("〇"), Some(LeaveStatus::Discharged));
+        assert_eq!(LeaveStatus::parse("退院済み"), Some(LeaveStatus::Discharged));
+        assert_eq!(LeaveStatus::parse("死亡"), Some(LeaveStatus::Died));
+        assert_eq!(LeaveStatus::parse("入院中"), Some(LeaveStatus::Hospitalized));
+        assert_eq!(LeaveStatus::parse("宿泊療養"), Some(LeaveStatus::Accommodation));
+        assert_eq!(LeaveStatus::parse("自宅 療養"), Some(LeaveStatus::HomeCare));
+        assert_eq!(LeaveStatus::parse("入院調整中"), Some(LeaveStatus::Adjusting));
+        assert_eq!(LeaveStatus::parse(""), Some(LeaveStatus::Unknown));
+    }
+
+    #[test]
+    fn parse_rejects_labels_outside_table() {
+        assert_eq!(LeaveStatus::parse("転院"), None);
+        assert_eq!(LeaveStatus::parse("×"), None);
+    }
+
+}
@@ -1,17 +1,11 @@
-use chrono::{DateTime, Local};
+use chrono::{DateTime, FixedOffset};
 use serde::ser::{Serialize, Serializer, SerializeStruct};
 
-#[derive(Clone)]
-pub struct Attribute {
-    pub attr: String,
-    pub value: i64
-}
-
 #[derive(Clone)]
 pub struct MainSummaryChildren {
     pub attr: String,
     pub value: i64,
-    pub children: Vec<Attribute>
+    pub children: Vec<MainSummaryChildren>
 }
 
 #[derive(Clone)]
@@ -19,30 +13,32 @@ pub struct MainSummary {
     pub attr: String,
     pub value: i64,
     pub children: Vec<MainSummaryChildren>,
-    pub last_update: DateTime<Local>
+    pub last_update: DateTime<FixedOffset>
 }
 
-impl Serialize for Attribute {
+impl Serialize for MainSummaryChildren {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("JsonizeSummary", 6)?;
+        let mut state = serializer.serialize_struct("MainSummaryChildren", 2 + usize::from(!self.children.is_empty()))?;
         state.serialize_field("attr", &self.attr)?;
         state.serialize_field("value", &self.value)?;
+        // 内訳の無い項目には、childrenを出力しない
+        if !self.children.is_empty() {
+            state.serialize_field("children", &self.children)?;
+        }
         state.end()
     }
 }
 
-impl Serialize for MainSummaryChildren {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut state = serializer.serialize_struct("MainSummaryChildren", 6)?;
-        state.serialize_field("attr", &self.attr)?;
-        state.serialize_field("value", &self.value)?;
-        state.serialize_field("children", &self.children)?;
-        state.end()
-    }
+// 主要な数値の木構造の1項目（attrはPCR検査件数ワークシートの見出し、columnはその列の位置）
+// countedがfalseの項目は内数などのため、親の値と内訳の合計との照合に含めない
+// knownがfalseの項目は既知の見出しに無い列のため、値が読めなくても出力を止めない
+pub struct MainSummaryNode {
+    pub attr: String,
+    pub column: usize,
+    pub counted: bool,
+    pub known: bool,
+    pub children: Vec<MainSummaryNode>
 }
@@ -0,0 +1,56 @@
+// 市区町村と、その全国地方公共団体コード（JIS X 0402）、所属する地域と管轄の保健所、人口
+pub struct Municipality {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub region: &'static str,
+    pub health_center: &'static str,
+    // 令和2年国勢調査の人口
+    pub population: u64
+}
+
+// 都道府県ごとの市区町村の辞書
+// 他の都道府県に対応する場合は、同じ形の辞書を追加する
+pub struct MunicipalityDictionary {
+    pub prefecture: &'static str,
+    // 都道府県の人口（市区町村の人口と同じ統計による）
+    pub population: u64,
+    // 都道府県外を表す表記（例: 府外、県外）
+    pub outside: &'static str,
+    pub municipalities: &'static [Municipality],
+    // 政令指定都市の区と、その区が属する市の名前
+    pub wards: &'static [(&'static str, &'static str)]
+}
+
+// 正規化した居住地
+#[derive(Clone, Copy)]
+pub enum Place {
+    Municipality(&'static Municipality),
+    OutsidePrefecture(&'static str),
+    Unknown
+}
+
+impl Place {
+
+    pub fn name(&self) -> String {
+        match self {
+            Place::Municipality(municipality) => municipality.name.to_string(),
+            Place::OutsidePrefecture(outside) => outside.to_string(),
+            Place::Unknown => String::from("")
+        }
+    }
+
+    pub fn code(&self) -> Option<String> {
+        match self {
+            Place::Municipality(municipality) => Some(municipality.code.to_string()),
+            _ => None
+        }
+    }
+
+    pub fn region(&self) -> Option<String> {
+        match self {
+            Place::Municipality(municipality) => Some(municipality.region.to_string()),
+            _ => None
+        }
+    }
+
+}
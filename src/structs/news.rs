@@ -9,7 +9,7 @@ impl Serialize for News {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("News", 6)?;
+        let mut state = serializer.serialize_struct("News", 1)?;
         state.serialize_field("newsItems", &self.news_items)?;
         state.end()
     }
@@ -26,7 +26,7 @@ impl Serialize for NewsItem {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("NewsItem", 6)?;
+        let mut state = serializer.serialize_struct("NewsItem", 3)?;
         state.serialize_field("date", &self.date)?;
         state.serialize_field("text", &self.text)?;
         state.serialize_field("url", &self.url)?;
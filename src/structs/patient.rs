@@ -1,11 +1,14 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset};
+use crate::structs::{age_group::AgeGroup, gender::Gender, leave_status::LeaveStatus, municipality::Place};
 
 #[derive(Clone)]
 pub struct Patient {
     pub number: i32,
-    pub release_date: Option<DateTime<Utc>>,
+    pub release_date: Option<DateTime<FixedOffset>>,
     pub place: Option<String>,
-    pub age: Option<String>,
-    pub gender: Option<String>,
-    pub leave: Option<String>
+    pub municipality: Place,
+    pub age: AgeGroup,
+    pub gender: Gender,
+    pub leave: Option<String>,
+    pub leave_status: LeaveStatus
 }
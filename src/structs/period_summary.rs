@@ -0,0 +1,52 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate};
+
+// 集計する期間の単位
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    // 月曜日から始まる週（ISO週）
+    Week,
+    // 暦月
+    Month
+}
+
+impl Period {
+
+    // 期間の名前（例: 2020-W26、2020-06）
+    pub fn label(&self, start: NaiveDate) -> String {
+        match self {
+            Period::Week => start.format("%G-W%V").to_string(),
+            Period::Month => start.format("%Y-%m").to_string()
+        }
+    }
+
+    // 期間の最後の日
+    pub fn end(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Period::Week => start + Duration::days(6),
+            Period::Month => match start.month() {
+                12 => NaiveDate::from_ymd_opt(start.year() + 1, 1, 1),
+                month => NaiveDate::from_ymd_opt(start.year(), month + 1, 1)
+            }.unwrap() - Duration::days(1)
+        }
+    }
+
+}
+
+// 1つの期間の合計
+#[derive(Clone)]
+pub struct PeriodData {
+    pub start: NaiveDate,
+    pub positives: i64,
+    pub inspections: i64,
+    pub deaths: i64,
+    pub recoveries: i64,
+    // 期間の最後の日が最終更新日より後で、まだ集計の途中である
+    pub in_progress: bool
+}
+
+#[derive(Clone)]
+pub struct PeriodSummary {
+    pub period: Period,
+    pub data: Vec<PeriodData>,
+    pub last_update: DateTime<FixedOffset>
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, FixedOffset};
+
+// ある日の陽性率と、その日までの7日間の陽性率
+// 検査実施人数が0以下の日は、陽性率を求めない
+#[derive(Clone)]
+pub struct PositivityData {
+    pub date: DateTime<FixedOffset>,
+    pub inspections: i64,
+    pub positives: i64,
+    pub rate: Option<f64>,
+    pub weekly_inspections: Option<i64>,
+    pub weekly_positives: Option<i64>,
+    pub weekly_rate: Option<f64>
+}
+
+#[derive(Clone)]
+pub struct Positivity {
+    pub data: Vec<PositivityData>,
+    // 陽性患者数の公表日に対して、何日前の検査実施人数と突き合わせたか
+    pub lag: i64,
+    pub last_update: DateTime<FixedOffset>
+}
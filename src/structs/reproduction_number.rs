@@ -0,0 +1,29 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+// 実効再生産数の推定に使う設定
+// 発症間隔はガンマ分布とし、Rtの事前分布はガンマ分布（形状母数、尺度母数）とする
+#[derive(Clone, Copy)]
+pub struct ReproductionNumberConfig {
+    pub serial_interval_mean: f64,
+    pub serial_interval_sd: f64,
+    pub prior_shape: f64,
+    pub prior_scale: f64,
+    // 推定に使う期間の日数
+    pub window: usize
+}
+
+// ある日までのwindow日間から推定した実効再生産数の事後分布の平均と95%信用区間
+#[derive(Clone)]
+pub struct ReproductionNumberData {
+    pub date: NaiveDate,
+    pub mean: f64,
+    pub lower: f64,
+    pub upper: f64
+}
+
+#[derive(Clone)]
+pub struct ReproductionNumber {
+    pub config: ReproductionNumberConfig,
+    pub data: Vec<ReproductionNumberData>,
+    pub last_update: DateTime<FixedOffset>
+}
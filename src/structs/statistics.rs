@@ -0,0 +1,22 @@
+use chrono::{DateTime, FixedOffset};
+
+// 日ごとの件数から求めた統計値
+// 7日分の件数が揃わない日は、7日間の値を求めない
+#[derive(Clone)]
+pub struct StatisticsData {
+    pub date: DateTime<FixedOffset>,
+    pub sum: i64,
+    pub weekly_sum: Option<i64>,
+    pub moving_average: Option<f64>,
+    // 前の週の同じ曜日の件数に対する比
+    pub week_over_week: Option<f64>,
+    pub per_100k: f64,
+    pub weekly_per_100k: Option<f64>
+}
+
+#[derive(Clone)]
+pub struct Statistics {
+    pub data: Vec<StatisticsData>,
+    pub population: u64,
+    pub last_update: DateTime<FixedOffset>
+}
@@ -1,7 +1,9 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset};
 
 #[derive(Clone)]
 pub struct SumData {
-    pub date: DateTime<Utc>,
-    pub sum: i64
+    pub date: DateTime<FixedOffset>,
+    pub sum: i64,
+    // 訂正や報告の無い日など、値をそのまま読めない理由
+    pub note: Option<String>
 }
@@ -1,8 +1,10 @@
-use chrono::{DateTime, Local};
+use chrono::{DateTime, FixedOffset};
 use crate::structs::sumdata::SumData;
 
 #[derive(Clone)]
 pub struct Summary {
     pub data: Vec<SumData>,
-    pub last_update: DateTime<Local>
+    // 日付が分からないため、どの日にも数えられなかった件数
+    pub undated: Option<i64>,
+    pub last_update: DateTime<FixedOffset>
 }
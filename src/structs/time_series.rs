@@ -0,0 +1,246 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use crate::structs::{summary::Summary, sumdata::SumData};
+use crate::utils::date_format::{convert_date_to_jst, convert_jst_to_date};
+
+// 1日ごとに隙間なく並んだ値の列
+// 日付は日本標準時での日付として扱い、startの日から順に1日1つの値を持つ
+#[derive(Clone)]
+pub struct TimeSeries<T> {
+    pub start: Option<NaiveDate>,
+    pub values: Vec<T>
+}
+
+impl<T: Clone> TimeSeries<T> {
+
+    // 日付と値の組から作る（値の無い日はNoneで補い、同じ日付が複数あれば後の値を使う）
+    pub fn from_points(points: Vec<(NaiveDate, T)>) -> TimeSeries<Option<T>> {
+
+        let points: BTreeMap<NaiveDate, T> = points.into_iter().collect();
+
+        let (start, end): (NaiveDate, NaiveDate) = match (points.keys().next(), points.keys().next_back()) {
+            (Some(start), Some(end)) => (*start, *end),
+            _ => return TimeSeries { start: None, values: Vec::new() }
+        };
+
+        let mut values: Vec<Option<T>> = Vec::new();
+        let mut date: NaiveDate = start;
+
+        while date <= end {
+            values.push(points.get(&date).cloned());
+            date += Duration::days(1);
+        }
+
+        TimeSeries {
+            start: Some(start),
+            values
+        }
+
+    }
+
+    pub fn get(&self, date: NaiveDate) -> Option<&T> {
+        let index: i64 = (date - self.start?).num_days();
+        usize::try_from(index).ok().and_then(|index| self.values.get(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (NaiveDate, &T)> + '_ {
+        let start: NaiveDate = self.start.unwrap_or(NaiveDate::MIN);
+        self.values.iter().enumerate().map(move |(i, value)| (start + Duration::days(i as i64), value))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> TimeSeries<U> {
+        TimeSeries {
+            start: self.start,
+            values: self.values.iter().map(f).collect()
+        }
+    }
+
+    // その日までのsize日間の値を集計する（size日分が揃わない最初の日はNone）
+    pub fn window<U, F: Fn(&[T]) -> U>(&self, size: usize, f: F) -> TimeSeries<Option<U>> {
+        TimeSeries {
+            start: self.start,
+            values: (0..self.values.len())
+                .map(|i| if size > 0 && i + 1 >= size { Some(f(&self.values[i + 1 - size..=i])) } else { None })
+                .collect()
+        }
+    }
+
+}
+
+impl<T: Clone> TimeSeries<Option<T>> {
+
+    // 値の無い日をvalueで補う
+    pub fn fill(&self, value: T) -> TimeSeries<T> {
+        self.map(|v| v.clone().unwrap_or_else(|| value.clone()))
+    }
+
+}
+
+impl TimeSeries<i64> {
+
+    // 出来事の日時から、日ごとの件数を数える（日時は日本標準時での日付に丸める）
+    pub fn from_events(datetimes: &[DateTime<FixedOffset>]) -> TimeSeries<i64> {
+
+        let mut counts: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+
+        for datetime in datetimes {
+            *counts.entry(convert_jst_to_date(*datetime)).or_insert(0) += 1;
+        }
+
+        TimeSeries::from_points(counts.into_iter().collect()).fill(0)
+
+    }
+
+    // Summaryの日ごとの件数から作る（件数の無い日は0で補う）
+    pub fn from_summary(summary: &Summary) -> TimeSeries<i64> {
+        TimeSeries::from_points(summary.data.iter().map(|data| (convert_jst_to_date(data.date), data.sum)).collect()).fill(0)
+    }
+
+    // 累計
+    pub fn cumulative(&self) -> TimeSeries<i64> {
+
+        let mut sum: i64 = 0;
+
+        self.map(|value| {
+            sum += value;
+            sum
+        })
+
+    }
+
+    pub fn window_sum(&self, size: usize) -> TimeSeries<Option<i64>> {
+        self.window(size, |values| values.iter().sum())
+    }
+
+    // 月曜日から始まる週（ISO週）ごとの合計（週の初日と合計の組）
+    pub fn weekly(&self) -> Vec<(NaiveDate, i64)> {
+        self.resample(|date| date - Duration::days(date.weekday().num_days_from_monday() as i64))
+    }
+
+    // 暦月ごとの合計（月の初日と合計の組）
+    pub fn monthly(&self) -> Vec<(NaiveDate, i64)> {
+        self.resample(|date| date.with_day(1).unwrap())
+    }
+
+    fn resample<F: Fn(NaiveDate) -> NaiveDate>(&self, period: F) -> Vec<(NaiveDate, i64)> {
+
+        let mut sums: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+
+        for (date, value) in self.iter() {
+            *sums.entry(period(date)).or_insert(0) += value;
+        }
+
+        sums.into_iter().collect()
+
+    }
+
+    pub fn to_summary(&self, last_update: DateTime<FixedOffset>) -> Summary {
+        Summary {
+            data: self.iter().map(|(date, value)| SumData {
+                date: convert_date_to_jst(date),
+                sum: *value,
+                note: None
+            }).collect(),
+            undated: None,
+            last_update
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::utils::date_format::jst;
+    use chrono::TimeZone;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn from_points_fills_gaps_and_keeps_last_duplicate() {
+        let series: TimeSeries<Option<i64>> = TimeSeries::from_points(vec![
+            (ymd(2021, 8, 3), 3), (ymd(2021, 8, 1), 1), (ymd(2021, 8, 3), 30)
+        ]);
+
+        assert_eq!(series.start, Some(ymd(2021, 8, 1)));
+        assert_eq!(series.values, vec![Some(1), None, Some(30)]);
+        assert_eq!(series.fill(0).values, vec![1, 0, 30]);
+    }
+
+    #[test]
+    fn from_points_handles_empty_input() {
+        let series: TimeSeries<Option<i64>> = TimeSeries::from_points(Vec::new());
+
+        assert_eq!(series.start, None);
+        assert!(series.values.is_empty());
+        assert_eq!(series.get(ymd(2021, 8, 1)), None);
+    }
+
+    #[test]
+    fn get_and_iter_follow_dates() {
+        let series: TimeSeries<i64> = TimeSeries { start: Some(ymd(2021, 8, 31)), values: vec![1, 2, 3] };
+
+        assert_eq!(series.get(ymd(2021, 9, 1)), Some(&2));
+        assert_eq!(series.get(ymd(2021, 8, 30)), None);
+        assert_eq!(series.get(ymd(2021, 9, 3)), None);
+        assert_eq!(
+            series.iter().map(|(date, value)| (date, *value)).collect::<Vec<(NaiveDate, i64)>>(),
+            vec![(ymd(2021, 8, 31), 1), (ymd(2021, 9, 1), 2), (ymd(2021, 9, 2), 3)]
+        );
+    }
+
+    #[test]
+    fn from_events_counts_by_jst_date() {
+        // UTCの15時以降は、日本標準時では翌日になる
+        let events: Vec<DateTime<FixedOffset>> = vec![
+            DateTime::parse_from_rfc3339("2021-08-01T14:59:00Z").unwrap(),
+            DateTime::parse_from_rfc3339("2021-08-01T15:00:00Z").unwrap(),
+            jst().with_ymd_and_hms(2021, 8, 4, 9, 0, 0).unwrap()
+        ];
+
+        let series: TimeSeries<i64> = TimeSeries::from_events(&events);
+
+        assert_eq!(series.start, Some(ymd(2021, 8, 1)));
+        assert_eq!(series.values, vec![1, 1, 0, 1]);
+    }
+
+    #[test]
+    fn cumulative_and_window_sum() {
+        let series: TimeSeries<i64> = TimeSeries { start: Some(ymd(2021, 8, 1)), values: vec![1, 2, 3, 4] };
+
+        assert_eq!(series.cumulative().values, vec![1, 3, 6, 10]);
+        assert_eq!(series.window_sum(3).values, vec![None, None, Some(6), Some(9)]);
+        assert_eq!(series.window_sum(0).values, vec![None, None, None, None]);
+    }
+
+    #[test]
+    fn weekly_starts_on_monday() {
+        // 2021年8月1日は日曜日
+        let series: TimeSeries<i64> = TimeSeries { start: Some(ymd(2021, 8, 1)), values: vec![1; 9] };
+
+        assert_eq!(series.weekly(), vec![(ymd(2021, 7, 26), 1), (ymd(2021, 8, 2), 7), (ymd(2021, 8, 9), 1)]);
+    }
+
+    #[test]
+    fn monthly_starts_on_first_day() {
+        let series: TimeSeries<i64> = TimeSeries { start: Some(ymd(2021, 8, 30)), values: vec![1, 2, 3, 4] };
+
+        assert_eq!(series.monthly(), vec![(ymd(2021, 8, 1), 3), (ymd(2021, 9, 1), 7)]);
+    }
+
+    #[test]
+    fn summary_round_trip() {
+        let series: TimeSeries<i64> = TimeSeries { start: Some(ymd(2021, 8, 1)), values: vec![5, 0, 7] };
+        let last_update: DateTime<FixedOffset> = jst().with_ymd_and_hms(2021, 8, 4, 0, 0, 0).unwrap();
+
+        let summary: Summary = series.to_summary(last_update);
+
+        assert_eq!(summary.data[1].date, jst().with_ymd_and_hms(2021, 8, 2, 0, 0, 0).unwrap());
+        assert_eq!(TimeSeries::from_summary(&summary).values, vec![5, 0, 7]);
+    }
+
+}
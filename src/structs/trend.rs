@@ -0,0 +1,49 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+// 陽性者数の傾向
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Flat,
+    Decreasing
+}
+
+impl Trend {
+
+    pub fn label(&self) -> String {
+        match self {
+            Trend::Increasing => String::from("増加"),
+            Trend::Flat => String::from("横ばい"),
+            Trend::Decreasing => String::from("減少")
+        }
+    }
+
+}
+
+// 傾向の判定に使う設定
+// 直近window日間の合計と、その前のwindow日間の合計の比がincrease以上なら増加、decrease以下なら減少とする
+#[derive(Clone, Copy)]
+pub struct TrendConfig {
+    pub window: usize,
+    pub increase: f64,
+    pub decrease: f64
+}
+
+// ある日までの陽性者数の傾向と、倍加時間または半減時間（日）
+#[derive(Clone)]
+pub struct TrendData {
+    pub date: NaiveDate,
+    pub sum: i64,
+    pub previous_sum: i64,
+    pub ratio: Option<f64>,
+    pub doubling_time: Option<f64>,
+    pub halving_time: Option<f64>,
+    pub trend: Trend
+}
+
+#[derive(Clone)]
+pub struct Trends {
+    pub config: TrendConfig,
+    pub data: Vec<TrendData>,
+    pub last_update: DateTime<FixedOffset>
+}
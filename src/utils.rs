@@ -1,2 +1,9 @@
+pub mod cell_address;
 pub mod date_format;
+pub mod detect_layout;
+pub mod find_worksheets;
+pub mod gamma;
 pub mod merge_age_and_gender;
+pub mod normalize_municipality;
+pub mod normalize_text;
+pub mod write_output;
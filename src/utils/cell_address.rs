@@ -0,0 +1,20 @@
+// ワークシート上の絶対位置（0始まりの行、列）をA1形式のセル番地に変換する
+pub fn convert_position_to_a1(position: (u32, u32)) -> String {
+
+    let (row, mut column) = position;
+    let mut column_str: String = String::from("");
+
+    // 列番号を26進数のアルファベット表記（A, B, ..., Z, AA, ...）に変換
+    loop {
+        column_str.insert(0, (b'A' + (column % 26) as u8) as char);
+
+        if column < 26 {
+            break;
+        }
+
+        column = column / 26 - 1;
+    }
+
+    format!("{}{}", column_str, row + 1)
+
+}
@@ -1,64 +1,252 @@
-use std::error;
-use std::fmt;
-use chrono::{DateTime, Local, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, TimeZone};
 use regex::Regex;
+use std::sync::LazyLock;
+use crate::errors::incorrect_format_error::IncorrectFormatError;
+use crate::utils::normalize_text::normalize_text;
 
-#[derive(Debug)]
-pub struct IncorrectFormatError {}
+// 日本標準時（UTC+09:00）
+// 実行環境のタイムゾーン（TZ環境変数）に依存しないよう、日時は全てこの時差で扱う
+pub fn jst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 60 * 60).unwrap()
+}
 
-impl fmt::Display for IncorrectFormatError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Incorrect format")
-    }
+// ワークシート上のタイムゾーンを持たない日時を、日本標準時の日時として解釈する
+pub fn convert_naive_to_jst(datetime: NaiveDateTime) -> DateTime<FixedOffset> {
+    DateTime::from_naive_utc_and_offset(datetime - Duration::hours(9), jst())
 }
 
-impl error::Error for IncorrectFormatError {
-    fn description(&self) -> &str {
-        "Incorrect format"
+// 日付を、その日の日本標準時の0時0分に変換する
+pub fn convert_date_to_jst(date: NaiveDate) -> DateTime<FixedOffset> {
+    convert_naive_to_jst(date.and_time(NaiveTime::MIN))
+}
+
+// 日時を、日本標準時での日付に変換する
+pub fn convert_jst_to_date(datetime: DateTime<FixedOffset>) -> NaiveDate {
+    datetime.with_timezone(&jst()).date_naive()
+}
+
+pub fn convert_datetime_to_date(datetime: DateTime<FixedOffset>) -> String {
+    datetime.with_timezone(&jst()).format("%Y-%m-%d").to_string()
+}
+
+// 更新日時を、日付の項目と同じRFC 3339形式（+09:00付き）の文字列に変換する
+pub fn convert_datetime_to_date_and_time(datetime: DateTime<FixedOffset>) -> String {
+    datetime.with_timezone(&jst()).to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+// 元号の名前と略記、期間（開始日と終了日）
+struct Era {
+    name: &'static str,
+    abbreviations: [&'static str; 3],
+    start: (i32, u32, u32),
+    end: Option<(i32, u32, u32)>
+}
+
+impl Era {
+
+    fn contains(&self, date: NaiveDate) -> bool {
+
+        let start: Option<NaiveDate> = NaiveDate::from_ymd_opt(self.start.0, self.start.1, self.start.2);
+        let end: Option<NaiveDate> = self.end.and_then(|end| NaiveDate::from_ymd_opt(end.0, end.1, end.2));
+
+        start.is_some_and(|start| start <= date) && end.is_none_or(|end| date <= end)
+
     }
+
 }
 
-pub fn convert_utc_to_date(datetime: DateTime<Utc>) -> String {
-    return datetime.format("%Y-%m-%d").to_string();
+const ERAS: [Era; 4] = [
+    Era { name: "大正", abbreviations: ["大", "T", "t"], start: (1912, 7, 30), end: Some((1926, 12, 24)) },
+    Era { name: "昭和", abbreviations: ["昭", "S", "s"], start: (1926, 12, 25), end: Some((1989, 1, 7)) },
+    Era { name: "平成", abbreviations: ["平", "H", "h"], start: (1989, 1, 8), end: Some((2019, 4, 30)) },
+    Era { name: "令和", abbreviations: ["令", "R", "r"], start: (2019, 5, 1), end: None }
+];
+
+// 日付を和暦の文字列（例: 令和3年8月1日、令和元年5月1日）に変換する
+pub fn convert_date_to_japanese_era(datetime: DateTime<FixedOffset>) -> String {
+
+    let date: NaiveDate = datetime.with_timezone(&jst()).date_naive();
+
+    match ERAS.iter().find(|era| era.contains(date)) {
+        Some(era) => {
+            let year: i32 = date.year() - era.start.0 + 1;
+            let year_str: String = if year == 1 { String::from("元") } else { year.to_string() };
+
+            format!("{}{}年{}月{}日", era.name, year_str, date.month(), date.day())
+        },
+        None => date.format("%Y年%-m月%-d日").to_string()
+    }
+
 }
 
-pub fn convert_datetime_to_date_and_time(datetime: DateTime<Local>) -> String {
-    return datetime.format("%Y/%m/%d %H:%M").to_string();
+// 日付の文字列を、その日の日本標準時の0時0分に変換する
+pub fn convert_japanese_date_to_jst(date_str: &str) -> Result<DateTime<FixedOffset>, IncorrectFormatError> {
+
+    let date: NaiveDate = parse_date(date_str)?;
+
+    jst().with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0)
+        .single()
+        .ok_or(IncorrectFormatError {})
+
 }
 
-pub fn convert_japanese_era_to_utc(date_str: &str) -> Result<DateTime<Utc>, IncorrectFormatError> {
+// 末尾の曜日（例: （日）、(日曜日)）
+static WEEKDAY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\s*[(（]\s*[月火水木金土日](曜日?)?\s*[)）]\s*$").unwrap()
+});
+
+// 元号（略記を含む）、年、月、日ごとに、区切り文字の揺れを許容するようにパターンマッチングする
+static ERA_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(大正|昭和|平成|令和|[大昭平令TSHRtshr])\s*(元|[0-9]{1,2})\s*(?:年|[./-])\s*([0-9]{1,2})\s*(?:月|[./-])\s*([0-9]{1,2})\s*日?$"
+    ).unwrap()
+});
+
+// 西暦の年月日（2021年8月1日、2021/8/1、2021-08-01T00:00:00など）
+static GREGORIAN_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^([0-9]{4})\s*(?:年|[./-])\s*([0-9]{1,2})\s*(?:月|[./-])\s*([0-9]{1,2})\s*日?(?:[T ][0-9]{1,2}:[0-9]{2}(?::[0-9]{2}(?:\.[0-9]+)?)?(?:Z|[+-][0-9]{2}:?[0-9]{2})?)?$"
+    ).unwrap()
+});
+
+// Excelのシリアル値（1899年12月30日からの日数）
+static SERIAL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]{5}(?:\.[0-9]+)?$").unwrap());
 
-    // 元号判定、年、月、日ごとに、末尾のスペースを許容するようにパターンマッチングする
-    let re: Regex = Regex::new("^(平成|令和)([元0-9]+)年([0-9]+)月([0-9]+)日( |)+$").unwrap();
-    let matches = re.captures(date_str).unwrap();
+// 日付として受け付けるシリアル値の範囲（2000年1月1日から2099年12月31日まで）
+const SERIAL_RANGE: std::ops::RangeInclusive<i64> = 36526..=73050;
 
-    if matches.len() == 6 {
-        let era: &str = matches.get(1).unwrap().as_str();
-        let mut year_str: &str = matches.get(2).unwrap().as_str();
-        let month_str: &str = matches.get(3).unwrap().as_str();
-        let day_str: &str = matches.get(4).unwrap().as_str();
+// 和暦、西暦、ISO 8601形式、Excelのシリアル値のいずれかで書かれた日付をパースする
+fn parse_date(date_str: &str) -> Result<NaiveDate, IncorrectFormatError> {
 
-        if year_str == "元" {
-            year_str = "1";
+    // 全角の数字や記号を半角に揃え、前後の空白と末尾の曜日（例: （日））を取り除く
+    let date_str: String = normalize_text(date_str);
+    let date_str: String = WEEKDAY_RE.replace(date_str.trim(), "").to_string();
+
+    if let Some(matches) = ERA_RE.captures(&date_str) {
+        let era: &Era = match ERAS.iter().find(|era| era.name == &matches[1] || era.abbreviations.contains(&&matches[1])) {
+            Some(era) => era,
+            None => return Err(IncorrectFormatError {})
+        };
+        let year: i32 = if &matches[2] == "元" { 1 } else { parse_number(&matches[2])? };
+
+        let date: NaiveDate = NaiveDate::from_ymd_opt(era.start.0 - 1 + year, parse_number(&matches[3])?, parse_number(&matches[4])?)
+            .ok_or(IncorrectFormatError {})?;
+
+        // 元号の期間外の日付（例: 平成35年、令和元年1月1日）は誤りとして扱う
+        if !era.contains(date) {
+            return Err(IncorrectFormatError {});
         }
 
-        let mut year: i32 = year_str.parse::<i32>().unwrap();
-        let month: u32 = month_str.parse::<u32>().unwrap();
-        let day: u32 = day_str.parse::<u32>().unwrap();
-
-        match era {
-            "平成" => {
-                year += 1988;
-            },
-            "令和" => {
-                year += 2018;
-            }
-            _ => return Err(IncorrectFormatError {})
+        return Ok(date);
+    }
+
+    if let Some(matches) = GREGORIAN_RE.captures(&date_str) {
+        return NaiveDate::from_ymd_opt(parse_number(&matches[1])?, parse_number(&matches[2])?, parse_number(&matches[3])?)
+            .ok_or(IncorrectFormatError {});
+    }
+
+    if SERIAL_RE.is_match(&date_str) {
+        let serial: f64 = date_str.parse().map_err(|_| IncorrectFormatError {})?;
+
+        // 日付として現実的な範囲外のシリアル値（例: 件数の「12」）は日付とみなさない
+        if !SERIAL_RANGE.contains(&(serial.trunc() as i64)) {
+            return Err(IncorrectFormatError {});
         }
 
-        return Ok(Utc.ymd(year, month, day).and_hms(8, 0, 0));
+        return NaiveDate::from_ymd_opt(1899, 12, 30)
+            .and_then(|epoch| epoch.checked_add_signed(Duration::days(serial.trunc() as i64)))
+            .ok_or(IncorrectFormatError {});
+    }
+
+    Err(IncorrectFormatError {})
+
+}
+
+fn parse_number<T: std::str::FromStr>(number_str: &str) -> Result<T, IncorrectFormatError> {
+    number_str.parse::<T>().map_err(|_| IncorrectFormatError {})
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn parse_date_accepts_gregorian_formats() {
+        assert_eq!(parse_date("2021年8月1日").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date("2021/8/1").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date("2021-08-01").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date("2021-08-01T00:00:00+09:00").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date("2021.8.1").unwrap(), ymd(2021, 8, 1));
+    }
+
+    #[test]
+    fn parse_date_normalizes_width_and_weekday() {
+        assert_eq!(parse_date("２０２１年８月１日（日）").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date(" 2021/8/1 (日曜日) ").unwrap(), ymd(2021, 8, 1));
     }
 
-    return Err(IncorrectFormatError {})
+    #[test]
+    fn parse_date_accepts_japanese_era() {
+        assert_eq!(parse_date("令和3年8月1日").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date("R3.8.1").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date("令和元年5月1日").unwrap(), ymd(2019, 5, 1));
+    }
+
+    #[test]
+    fn parse_date_accepts_serial_in_range() {
+        assert_eq!(parse_date("44409").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date("44409.5").unwrap(), ymd(2021, 8, 1));
+        assert_eq!(parse_date("36526").unwrap(), ymd(2000, 1, 1));
+        assert_eq!(parse_date("73050").unwrap(), ymd(2099, 12, 31));
+    }
+
+    #[test]
+    fn parse_date_rejects_implausible_serial() {
+        assert!(parse_date("12").is_err());
+        assert!(parse_date("36525").is_err());
+        assert!(parse_date("73051").is_err());
+        assert!(parse_date("1234567").is_err());
+    }
+
+    #[test]
+    fn parse_date_rejects_invalid_dates() {
+        assert!(parse_date("").is_err());
+        assert!(parse_date("2021/2/30").is_err());
+        assert!(parse_date("調査中").is_err());
+    }
+
+
+    #[test]
+    fn parse_date_checks_era_boundaries() {
+        assert_eq!(parse_date("平成31年4月30日").unwrap(), ymd(2019, 4, 30));
+        assert!(parse_date("平成31年5月1日").is_err());
+        assert!(parse_date("令和元年4月30日").is_err());
+        assert_eq!(parse_date("昭和64年1月7日").unwrap(), ymd(1989, 1, 7));
+        assert!(parse_date("昭和64年1月8日").is_err());
+        assert_eq!(parse_date("平成元年1月8日").unwrap(), ymd(1989, 1, 8));
+        assert!(parse_date("平成35年1月1日").is_err());
+    }
+
+    #[test]
+    fn convert_date_to_japanese_era_switches_on_boundaries() {
+        assert_eq!(convert_date_to_japanese_era(convert_date_to_jst(ymd(2019, 4, 30))), "平成31年4月30日");
+        assert_eq!(convert_date_to_japanese_era(convert_date_to_jst(ymd(2019, 5, 1))), "令和元年5月1日");
+        assert_eq!(convert_date_to_japanese_era(convert_date_to_jst(ymd(1989, 1, 7))), "昭和64年1月7日");
+        assert_eq!(convert_date_to_japanese_era(convert_date_to_jst(ymd(1989, 1, 8))), "平成元年1月8日");
+        assert_eq!(convert_date_to_japanese_era(convert_date_to_jst(ymd(2021, 8, 1))), "令和3年8月1日");
+    }
+
+
+    #[test]
+    fn convert_datetime_to_date_and_time_keeps_jst_offset() {
+        let utc: DateTime<FixedOffset> = DateTime::parse_from_rfc3339("2021-08-01T15:30:00Z").unwrap();
+
+        assert_eq!(convert_datetime_to_date_and_time(utc), "2021-08-02T00:30:00.000+09:00");
+    }
 
 }
@@ -0,0 +1,260 @@
+use calamine::{DataType, Range};
+use crate::structs::layout::{Layout, SheetLayout, WorksheetGroup};
+use crate::utils::normalize_text::normalize_text;
+
+// これまでに受け取ったワークブックのレイアウト（古い順）
+// 新しいレイアウトのワークブックを受け取った場合は、ここに追加する
+pub const LAYOUTS: &[Layout] = &[
+    // 現行のレイアウト
+    // 列の並びは、これまでの処理が読んでいた列の位置（例: 陽性者の属性の6列目が退院、PCR検査件数の4列目が退院）に合わせたもの
+    // PCR検査件数の見出しは、従来のmain_summary.jsonの項目名をそのまま使っている
+    Layout {
+        version: "v1",
+        sheets: &[
+            SheetLayout {
+                name: "陽性者の属性",
+                required: true,
+                columns: &["通し番号", "公表日", "年齢", "性別", "居住地", "退院"]
+            },
+            SheetLayout {
+                name: "PCR検査件数",
+                required: true,
+                columns: &["日付", "検査実施人数", "陽性患者数", "退院", "入院中・入院調整中", "高度重症病床", "その他", "宿泊施設", "自宅療養", "死亡", "調整中"]
+            },
+            SheetLayout {
+                name: "最新の情報",
+                required: false,
+                columns: &["日付", "内容", "URL"]
+            }
+        ]
+    }
+];
+
+// いずれかのレイアウトに含まれるワークシートの名前
+pub fn layout_worksheet_names() -> Vec<&'static str> {
+
+    let mut names: Vec<&'static str> = Vec::new();
+
+    for layout in LAYOUTS {
+        for sheet in layout.sheets {
+            if !names.contains(&sheet.name) {
+                names.push(sheet.name);
+            }
+        }
+    }
+
+    names
+
+}
+
+// 先頭の行が見出し行であれば、その見出しを返す
+// 既知の見出しが2つ以上含まれている行を見出し行とみなす
+pub fn header_row(range: &Range<DataType>, name: &str) -> Option<Vec<String>> {
+
+    let row: Vec<String> = range.rows().next()?
+        .iter()
+        .map(|cell| normalize_text(&cell.to_string()))
+        .collect();
+
+    let known: usize = row.iter()
+        .filter(|label| LAYOUTS.iter()
+            .filter_map(|layout| layout.sheet(name))
+            .any(|sheet| sheet.column(label).is_some()))
+        .count();
+
+    if known >= 2 { Some(row) } else { None }
+
+}
+
+// 読み込んだワークシートの組み合わせと、列数、見出し行からレイアウトを判定する
+// レイアウトの列より右にある列は判定に使わない
+// 一致するレイアウトが無い場合は、最も近いレイアウトとの差分を返す
+pub fn detect_layout(worksheets: &[WorksheetGroup]) -> Result<&'static Layout, String> {
+
+    let mut closest: Option<(&'static Layout, Vec<String>)> = None;
+
+    for layout in LAYOUTS {
+        let differences: Vec<String> = compare_layout(layout, worksheets);
+
+        if differences.is_empty() {
+            return Ok(layout);
+        }
+
+        if closest.as_ref().is_none_or(|(_, closest_differences)| differences.len() <= closest_differences.len()) {
+            closest = Some((layout, differences));
+        }
+    }
+
+    Err(match closest {
+        Some((layout, differences)) => format!(
+            "unknown workbook layout, the closest known layout is {}:\n  {}",
+            layout.version,
+            differences.join("\n  ")
+        ),
+        None => String::from("unknown workbook layout")
+    })
+
+}
+
+// レイアウトとワークブックの差分を列挙する
+fn compare_layout(layout: &Layout, worksheets: &[WorksheetGroup]) -> Vec<String> {
+
+    let mut differences: Vec<String> = Vec::new();
+
+    for (name, sheets) in worksheets {
+        let sheet_layout: &SheetLayout = match layout.sheet(name) {
+            Some(sheet_layout) => sheet_layout,
+            None => {
+                if !sheets.is_empty() {
+                    differences.push(format!("{}: unexpected worksheet", name));
+                }
+                continue;
+            }
+        };
+
+        if sheets.is_empty() && sheet_layout.required {
+            differences.push(format!("{}: missing worksheet", name));
+        }
+
+        // 右側に列が追加されていても、レイアウトの列が全て揃っていれば一致とみなす
+        for (sheet_name, range) in sheets {
+            if range.width() < sheet_layout.columns.len() {
+                differences.push(format!(
+                    "{}: {} columns (expected at least {})", sheet_name, range.width(), sheet_layout.columns.len()
+                ));
+            }
+
+            if let Some(header) = header_row(range, name) {
+                for (i, column) in sheet_layout.columns.iter().enumerate() {
+                    let label: String = header.get(i).cloned().unwrap_or_default();
+
+                    if label != normalize_text(column) {
+                        differences.push(format!(
+                            "{}: column {} is {:?} (expected {:?})", sheet_name, i + 1, label, column
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    differences
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::structs::layout::Worksheet;
+
+    // 見出し行と1行のデータを持つワークシート
+    fn sheet(name: &str, header: &[&str]) -> Worksheet {
+        let mut range: Range<DataType> = Range::new((0, 0), (1, header.len() as u32 - 1));
+
+        for (i, label) in header.iter().enumerate() {
+            range.set_value((0, i as u32), DataType::String(label.to_string()));
+            range.set_value((1, i as u32), DataType::Float(1.0));
+        }
+
+        (name.to_string(), range)
+    }
+
+    // 見出し行の無いワークシート
+    fn data_sheet(name: &str, width: usize) -> Worksheet {
+        let mut range: Range<DataType> = Range::new((0, 0), (0, width as u32 - 1));
+
+        for i in 0..width {
+            range.set_value((0, i as u32), DataType::Float(1.0));
+        }
+
+        (name.to_string(), range)
+    }
+
+    fn workbook(patients: Vec<Worksheet>, inspections: Vec<Worksheet>, news: Vec<Worksheet>) -> Vec<WorksheetGroup> {
+        vec![("陽性者の属性", patients), ("PCR検査件数", inspections), ("最新の情報", news)]
+    }
+
+    fn columns(name: &str) -> Vec<&'static str> {
+        LAYOUTS[0].sheet(name).unwrap().columns.to_vec()
+    }
+
+    #[test]
+    fn detects_layout_from_headers() {
+        let worksheets: Vec<WorksheetGroup> = workbook(
+            vec![sheet("陽性者の属性", &columns("陽性者の属性"))],
+            vec![sheet("PCR検査件数", &columns("PCR検査件数"))],
+            vec![sheet("最新の情報", &columns("最新の情報"))]
+        );
+
+        assert_eq!(detect_layout(&worksheets).unwrap().version, "v1");
+    }
+
+    #[test]
+    fn detects_layout_without_headers_or_optional_sheet() {
+        let worksheets: Vec<WorksheetGroup> = workbook(
+            vec![data_sheet("陽性者の属性", 6)],
+            vec![data_sheet("PCR検査件数", 11)],
+            Vec::new()
+        );
+
+        assert!(detect_layout(&worksheets).is_ok());
+    }
+
+    #[test]
+    fn allows_extra_columns_on_the_right() {
+        let mut inspections: Vec<&str> = columns("PCR検査件数");
+        inspections.push("新しい列");
+
+        let worksheets: Vec<WorksheetGroup> = workbook(
+            vec![sheet("陽性者の属性", &columns("陽性者の属性"))],
+            vec![sheet("PCR検査件数", &inspections)],
+            Vec::new()
+        );
+
+        assert!(detect_layout(&worksheets).is_ok());
+    }
+
+    #[test]
+    fn reports_missing_columns() {
+        let worksheets: Vec<WorksheetGroup> = workbook(
+            vec![data_sheet("陽性者の属性", 5)],
+            vec![data_sheet("PCR検査件数", 11)],
+            Vec::new()
+        );
+
+        let error: String = detect_layout(&worksheets).err().unwrap();
+
+        assert!(error.contains("陽性者の属性: 5 columns (expected at least 6)"));
+    }
+
+    #[test]
+    fn reports_mismatched_headers() {
+        let mut inspections: Vec<&str> = columns("PCR検査件数");
+        inspections[3] = "退院者数";
+
+        let worksheets: Vec<WorksheetGroup> = workbook(
+            vec![sheet("陽性者の属性", &columns("陽性者の属性"))],
+            vec![sheet("PCR検査件数", &inspections)],
+            Vec::new()
+        );
+
+        let error: String = detect_layout(&worksheets).err().unwrap();
+
+        assert!(error.contains("PCR検査件数: column 4 is \"退院者数\" (expected \"退院\")"));
+    }
+
+    #[test]
+    fn reports_missing_required_worksheet() {
+        let worksheets: Vec<WorksheetGroup> = workbook(
+            Vec::new(),
+            vec![sheet("PCR検査件数", &columns("PCR検査件数"))],
+            Vec::new()
+        );
+
+        let error: String = detect_layout(&worksheets).err().unwrap();
+
+        assert!(error.contains("陽性者の属性: missing worksheet"));
+    }
+
+}
@@ -0,0 +1,65 @@
+use regex::Regex;
+use crate::utils::normalize_text::normalize_text;
+
+// 表記揺れ（前後の空白、全角の括弧など）を吸収した上で、指定した名前のワークシートと
+// その続きのシート（例: 陽性者の属性(1)、陽性者の属性(2)）を番号順に並べて返す
+pub fn find_worksheets(sheet_names: &[String], name: &str) -> Vec<String> {
+
+    let re: Regex = Regex::new(&format!(
+        r"^{}(?:\(([0-9]+)\)|[-_]?([0-9]+)|その([0-9]+))?$",
+        regex::escape(name)
+    )).unwrap();
+
+    let mut worksheets: Vec<(u32, usize, String)> = Vec::new();
+
+    for (i, sheet_name) in sheet_names.iter().enumerate() {
+        let normalized: String = normalize_text(sheet_name).chars().filter(|c| !c.is_whitespace()).collect();
+
+        if let Some(matches) = re.captures(&normalized) {
+            // 番号の無いシートは先頭に並べ、同じ番号のシートはワークブック内の順番に並べる
+            let number: u32 = (1..=3)
+                .find_map(|group| matches.get(group))
+                .and_then(|number| number.as_str().parse().ok())
+                .unwrap_or(0);
+
+            worksheets.push((number, i, sheet_name.clone()));
+        }
+    }
+
+    worksheets.sort();
+
+    worksheets.into_iter().map(|(_, _, sheet_name)| sheet_name).collect()
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn names(sheet_names: &[&str]) -> Vec<String> {
+        sheet_names.iter().map(|sheet_name| sheet_name.to_string()).collect()
+    }
+
+    #[test]
+    fn finds_sheet_with_notation_variants() {
+        assert_eq!(find_worksheets(&names(&["最新の情報", " PCR検査件数 ", "陽性者の属性"]), "PCR検査件数"), names(&[" PCR検査件数 "]));
+        assert_eq!(find_worksheets(&names(&["ＰＣＲ検査件数"]), "PCR検査件数"), names(&["ＰＣＲ検査件数"]));
+    }
+
+    #[test]
+    fn orders_continuation_sheets_by_number() {
+        let sheet_names: Vec<String> = names(&["陽性者の属性(2)", "陽性者の属性（10）", "陽性者の属性", "陽性者の属性_3", "陽性者の属性その4"]);
+
+        assert_eq!(
+            find_worksheets(&sheet_names, "陽性者の属性"),
+            names(&["陽性者の属性", "陽性者の属性(2)", "陽性者の属性_3", "陽性者の属性その4", "陽性者の属性（10）"])
+        );
+    }
+
+    #[test]
+    fn ignores_other_sheets() {
+        assert!(find_worksheets(&names(&["陽性者の属性まとめ", "旧陽性者の属性"]), "陽性者の属性").is_empty());
+    }
+
+}
@@ -1,25 +1,56 @@
-pub fn merge_age_and_gender(age: &str, gender: &str) -> String {
+use crate::structs::{age_group::AgeGroup, gender::Gender};
+
+pub fn merge_age_and_gender(age: AgeGroup, gender: Gender) -> String {
+
+    // 年代と性別のどちらも非公表の場合は、まとめて非公表とする
+    if age == AgeGroup::Undisclosed && gender == Gender::Undisclosed {
+        return String::from("非公表");
+    }
 
     let mut result: String = String::from("");
 
-    // 空になっていないか確認し、またハイフン（情報なし）になっていないか確認
-    if age != "-" && !(age.is_empty()) {
-        // 年齢の末尾が以上で終わっていた場合（90歳や100歳以上など）
-        if age.ends_with("以上") {
-            result.push_str(&age.replace("以上", "歳以上"));
-        } else if age.ends_with("未満") {
-            result.push_str(&age.replace("未満", "歳未満"));
-        } else {
-            result.push_str(&age);
-            result.push_str("代");
-        }
+    // 片方だけが非公表の場合は、どちらが非公表なのかを明記する
+    if age == AgeGroup::Undisclosed && gender != Gender::Unknown {
+        result.push_str("年代非公表");
+    } else {
+        result.push_str(&age.label());
+    }
+
+    if gender == Gender::Undisclosed && age != AgeGroup::Unknown {
+        result.push_str("性別非公表");
+    } else {
+        result.push_str(&gender.label());
+    }
+
+    result
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn merges_known_age_and_gender() {
+        assert_eq!(merge_age_and_gender(AgeGroup::Decade(20), Gender::Male), "20代男性");
+        assert_eq!(merge_age_and_gender(AgeGroup::UnderTen, Gender::Female), "10歳未満女性");
+        assert_eq!(merge_age_and_gender(AgeGroup::NinetyAndOver, Gender::Male), "90歳以上男性");
     }
-    
-    // 空になっていないか確認し、またハイフン（情報なし）になっていないか確認
-    if gender != "-" && !(gender.is_empty()) {
-        result.push_str(&gender);
+
+    #[test]
+    fn leaves_unknown_part_empty() {
+        assert_eq!(merge_age_and_gender(AgeGroup::Unknown, Gender::Female), "女性");
+        assert_eq!(merge_age_and_gender(AgeGroup::Decade(40), Gender::Unknown), "40代");
+        assert_eq!(merge_age_and_gender(AgeGroup::Unknown, Gender::Unknown), "");
     }
 
-    return result;
+    #[test]
+    fn states_which_part_is_undisclosed() {
+        assert_eq!(merge_age_and_gender(AgeGroup::Undisclosed, Gender::Undisclosed), "非公表");
+        assert_eq!(merge_age_and_gender(AgeGroup::Undisclosed, Gender::Male), "年代非公表男性");
+        assert_eq!(merge_age_and_gender(AgeGroup::Decade(30), Gender::Undisclosed), "30代性別非公表");
+        assert_eq!(merge_age_and_gender(AgeGroup::Undisclosed, Gender::Unknown), "非公表");
+    }
 
 }
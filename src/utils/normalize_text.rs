@@ -0,0 +1,60 @@
+use unicode_normalization::UnicodeNormalization;
+
+// セルの文字列をNFKCで正規化し、ハイフンの揺れと空白を揃える
+// 例: 全角の英数字や空白、半角カタカナ、末尾の改行、「‐」「−」などのハイフン
+// 長音符「ー」は、かなの後にある場合は長音として残し、それ以外（例: 1ー2）はハイフンとして扱う
+pub fn normalize_text(text: &str) -> String {
+
+    let mut previous: Option<char> = None;
+    let normalized: String = text.nfkc()
+        .map(|c| {
+            let mapped: char = match c {
+                '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}' | '\u{2015}' | '\u{2212}' => '-',
+                '\u{30FC}' if !previous.is_some_and(is_kana) => '-',
+                _ => c
+            };
+            // ハイフンとして扱った長音符の後に続く長音符も、ハイフンとして扱う
+            previous = Some(mapped);
+            mapped
+        })
+        .collect();
+
+    // 改行を含む連続した空白を1つの空白にまとめ、前後の空白を取り除く
+    normalized.split_whitespace().collect::<Vec<&str>>().join(" ")
+
+}
+
+// ひらがなとカタカナ（長音符自体を含む）
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{309F}' | '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}')
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn normalizes_width_and_whitespace() {
+        assert_eq!(normalize_text("２０２１年　８月"), "2021年 8月");
+        assert_eq!(normalize_text("ｶﾀｶﾅ"), "カタカナ");
+        assert_eq!(normalize_text(" 京都市\n中京区 \n"), "京都市 中京区");
+    }
+
+    #[test]
+    fn unifies_hyphens() {
+        assert_eq!(normalize_text("2021‐08−01"), "2021-08-01");
+        assert_eq!(normalize_text("2021―08–01"), "2021-08-01");
+    }
+
+    #[test]
+    fn maps_prolonged_sound_mark_only_outside_kana() {
+        assert_eq!(normalize_text("1ー2"), "1-2");
+        assert_eq!(normalize_text("ー"), "-");
+        assert_eq!(normalize_text("1ーー2"), "1--2");
+        assert_eq!(normalize_text("センター"), "センター");
+        assert_eq!(normalize_text("ｾﾝﾀｰ"), "センター");
+        assert_eq!(normalize_text("すーぱーー"), "すーぱーー");
+    }
+
+}
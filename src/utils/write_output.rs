@@ -0,0 +1,15 @@
+use std::fs::File;
+use std::io::Write;
+use crate::errors::app_error::AppError;
+
+// 出力先のファイルに文字列を書き込む
+pub fn write_output(path: &str, content: &str) -> Result<(), AppError> {
+
+    let mut file = File::create(path)
+        .map_err(|e| AppError::Output(format!("{}: {}", path, e)))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| AppError::Output(format!("{}: {}", path, e)))?;
+
+    Ok(())
+
+}